@@ -67,6 +67,10 @@ fn iter_mut_benchmark(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::new("rows_mut", size), &size, |b, _| {
             b.iter(|| black_box(view.rows_mut().map(|r| r.iter_mut().map(|x| *x).sum::<u32>()).sum::<u32>()));
         });
+        // row-by-row flattening, i.e., what `cells_mut()` does for non-contiguous views
+        group.bench_with_input(BenchmarkId::new("rows_mut_flatten", size), &size, |b, _| {
+            b.iter(|| black_box(view.rows_mut().flatten().map(|x| *x).sum::<u32>()));
+        });
     }
 }
 
@@ -107,6 +111,16 @@ fn insert_benchmark(c: &mut Criterion) {
             });
         }
         
+        // insert_col_from_slice
+        {
+            let new_col : Vec<u32> = new_data.clone().collect();
+            // reserves space to exclude memory allocation from benchmark time
+            group.bench_with_input(BenchmarkId::new("insert_col_from_slice", size), &size, |b, _| {
+                b.iter_batched(|| { let mut tmp = toodee.clone(); tmp.reserve(size); tmp },
+                |mut data| data.insert_col_from_slice(0, &new_col), BatchSize::LargeInput)
+            });
+        }
+
         // insert_col_alloc
         {
             // reserves space to exclude memory allocation from benchmark time
@@ -145,5 +159,59 @@ fn remove_benchmark(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, fill_benchmark, iter_benchmark, iter_mut_benchmark, insert_benchmark, remove_benchmark);
+fn transpose_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transpose");
+    let size = 1024usize;
+    group.throughput(Throughput::Elements((size * size) as u64));
+    let toodee = new_rnd_toodee(size, size);
+
+    // straightforward, column-by-column transpose
+    group.bench_with_input(BenchmarkId::new("transpose_simple", size), &size, |b, _| {
+        b.iter_batched(|| toodee.clone(),
+        |data| {
+            let mut v = Vec::with_capacity(size * size);
+            for col in 0..size {
+                v.extend(data.col(col).copied());
+            }
+            black_box(TooDee::from_vec(size, size, v))
+        }, BatchSize::LargeInput)
+    });
+
+    for block in [16usize, 32, 64] {
+        group.bench_with_input(BenchmarkId::new(format!("transpose_blocked_{}", block), size), &size, |b, _| {
+            b.iter_batched(|| toodee.clone(),
+            |mut data| { data.transpose_blocked(block); black_box(data) }, BatchSize::LargeInput)
+        });
+    }
+
+    group.bench_with_input(BenchmarkId::new("transpose_in_place_rect", size), &size, |b, _| {
+        b.iter_batched(|| toodee.clone(),
+        |mut data| { data.transpose_in_place_rect(); black_box(data) }, BatchSize::LargeInput)
+    });
+
+    group.bench_with_input(BenchmarkId::new("transpose_square", size), &size, |b, _| {
+        b.iter_batched(|| toodee.clone(),
+        |mut data| { data.transpose_square(); black_box(data) }, BatchSize::LargeInput)
+    });
+}
+
+fn to_owned_transposed_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_owned_transposed");
+    let toodee = new_rnd_toodee(1024, 1024);
+    let view = toodee.view((10, 10), (1010, 1010));
+    let size = view.num_cols() * view.num_rows();
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(BenchmarkId::new("from_view_then_transpose", size), &size, |b, _| {
+        b.iter(|| {
+            let mut data = TooDee::from(view);
+            data.transpose_in_place_rect();
+            black_box(data)
+        });
+    });
+    group.bench_with_input(BenchmarkId::new("to_owned_transposed", size), &size, |b, _| {
+        b.iter(|| black_box(view.to_owned_transposed()));
+    });
+}
+
+criterion_group!(benches, fill_benchmark, iter_benchmark, iter_mut_benchmark, insert_benchmark, remove_benchmark, transpose_benchmark, to_owned_transposed_benchmark);
 criterion_main!(benches);
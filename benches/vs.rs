@@ -1,7 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use toodee::TooDee;
 use grid::Grid;
-use rand;
 use rand::Rng;
 
 const SIZE: usize = 1_000;
@@ -32,7 +31,7 @@ fn bench_idx(c: &mut Criterion) {
     group.bench_function("vecvec", |b| {
         let vec_vec = init_vec_vec();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| {
                 let _v = vec_vec[x][y];
             },
@@ -42,7 +41,7 @@ fn bench_idx(c: &mut Criterion) {
     group.bench_function("grid", |b| {
         let grid = init_grid();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| {
                 let _v = grid[(x,y)];
             },
@@ -52,7 +51,7 @@ fn bench_idx(c: &mut Criterion) {
     group.bench_function("toodee", |b| {
         let toodee = init_toodee();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| {
                 let _v = toodee[x][y];
             },
@@ -67,7 +66,7 @@ fn bench_get(c: &mut Criterion) {
     group.bench_function("vecvec", |b| {
         let vec_vec = init_vec_vec();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| {
                 let _v = vec_vec.get(x).unwrap().get(y).unwrap();
             },
@@ -77,7 +76,7 @@ fn bench_get(c: &mut Criterion) {
     group.bench_function("grid", |b| {
         let grid = init_grid();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| {
                 let _v = grid.get(x, y).unwrap();
             },
@@ -87,7 +86,7 @@ fn bench_get(c: &mut Criterion) {
     group.bench_function("toodee", |b| {
         let toodee = init_toodee();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| {
                 let _v = toodee[(x,y)];
             },
@@ -102,7 +101,7 @@ fn bench_set(c: &mut Criterion) {
     group.bench_function("vecvec", |b| {
         let mut vec_vec = init_vec_vec();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| vec_vec[x][y] = 42,
             criterion::BatchSize::SmallInput,
         )
@@ -110,7 +109,7 @@ fn bench_set(c: &mut Criterion) {
     group.bench_function("grid", |b| {
         let mut g = init_grid();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| g[(x,y)] = 42,
             criterion::BatchSize::SmallInput,
         )
@@ -118,7 +117,7 @@ fn bench_set(c: &mut Criterion) {
     group.bench_function("toodee", |b| {
         let mut toodee = init_toodee();
         b.iter_batched(
-            || create_rand_tuple(),
+            create_rand_tuple,
             |(x, y)| toodee[x][y] = 42,
             criterion::BatchSize::SmallInput,
         )
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+
+use crate::toodee::*;
+use crate::error::*;
+
+/// Builds a `TooDee` one row at a time.
+///
+/// # Examples
+///
+/// ```
+/// use toodee::{TooDeeBuilder,TooDeeOps};
+/// let toodee = TooDeeBuilder::new(3).row([1, 2, 3]).row([4, 5, 6]).build().unwrap();
+/// assert_eq!(toodee.size(), (3, 2));
+/// assert_eq!(toodee[1], [4, 5, 6]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TooDeeBuilder<T> {
+    data: Vec<T>,
+    num_cols: usize,
+    num_rows: usize,
+    error: Option<TooDeeError>,
+}
+
+impl<T> TooDeeBuilder<T> {
+
+    /// Creates a builder for an array with `num_cols` columns.
+    pub fn new(num_cols: usize) -> TooDeeBuilder<T> {
+        TooDeeBuilder {
+            data: Vec::new(),
+            num_cols,
+            num_rows: 0,
+            error: None,
+        }
+    }
+
+    /// Appends a row. If the row's length doesn't match `num_cols`, the error is reported
+    /// by `build()`.
+    pub fn row(mut self, row: impl IntoIterator<Item=T>) -> TooDeeBuilder<T> {
+        if self.error.is_none() {
+            let start = self.data.len();
+            self.data.extend(row);
+            let actual = self.data.len() - start;
+            if actual != self.num_cols {
+                self.data.truncate(start);
+                self.error = Some(TooDeeError::LengthMismatch { expected: self.num_cols, actual });
+            } else {
+                self.num_rows += 1;
+            }
+        }
+        self
+    }
+
+    /// Creates the `TooDee` from the rows that have been added.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooDeeError::LengthMismatch` for the first row whose length didn't match `num_cols`,
+    /// or `TooDeeError::InconsistentZeroDimension` if no rows were added to a builder with non-zero
+    /// `num_cols` (or vice versa).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDeeBuilder,TooDeeError};
+    /// let err = TooDeeBuilder::new(3).row([1, 2, 3]).row([4, 5]).build().unwrap_err();
+    /// assert_eq!(err, TooDeeError::LengthMismatch { expected: 3, actual: 2 });
+    /// ```
+    pub fn build(self) -> Result<TooDee<T>, TooDeeError> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        checked_size(self.num_cols, self.num_rows)?;
+        Ok(TooDee::from_vec(self.num_cols, self.num_rows, self.data))
+    }
+}
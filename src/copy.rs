@@ -5,6 +5,7 @@ use core::cmp::Ordering;
 use crate::toodee::*;
 use crate::view::*;
 use crate::ops::*;
+use crate::error::*;
 
 /// Provides basic copying operations for `TooDee` structures.
 pub trait CopyOps<T> : TooDeeOpsMut<T> {
@@ -59,7 +60,7 @@ pub trait CopyOps<T> : TooDeeOpsMut<T> {
     /// toodee.view_mut((5, 1), (10, 2)).copy_from_toodee(&ascending);
     /// ```
     fn copy_from_toodee(&mut self, src: &impl TooDeeOps<T>) where T : Copy {
-        assert_eq!(self.size(), src.size());
+        assert_same_dims(self, src);
         // Data is copied row by row.
         for (d, s) in self.rows_mut().zip(src.rows()) {
             d.copy_from_slice(s);
@@ -78,13 +79,37 @@ pub trait CopyOps<T> : TooDeeOpsMut<T> {
     /// toodee.view_mut((5, 1), (10, 2)).clone_from_toodee(&ascending);
     /// ```
     fn clone_from_toodee(&mut self, src: &impl TooDeeOps<T>) where T : Clone {
-        assert_eq!(self.size(), src.size());
+        assert_same_dims(self, src);
         // Data is copied row by row.
         for (d, s) in self.rows_mut().zip(src.rows()) {
             d.clone_from_slice(s);
         }
     }
 
+    /// Exchanges the contents of this area with another area of the same dimensions, row by row.
+    /// Nothing is cloned, and no temporary buffer is allocated.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the dimensions of the two areas differ.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut,CopyOps};
+    /// let mut a = TooDee::from_vec(2, 1, vec![1, 2]);
+    /// let mut b = TooDee::from_vec(3, 2, vec![3, 4, 5, 6, 7, 8]);
+    /// a.swap_with(&mut b.view_mut((1, 1), (3, 2)));
+    /// assert_eq!(a.data(), &[7, 8]);
+    /// assert_eq!(b.data(), &[3, 4, 5, 6, 1, 2]);
+    /// ```
+    fn swap_with(&mut self, other: &mut impl TooDeeOpsMut<T>) {
+        assert_same_dims(self, other);
+        for (a, b) in self.rows_mut().zip(other.rows_mut()) {
+            a.swap_with_slice(b);
+        }
+    }
+
     /// Copies the `src` area (top-left to bottom-right) to a destination area. `dest` specifies
     /// the top-left position of destination area. The `src` area will be partially overwritten
     /// if the regions overlap.
@@ -142,6 +167,35 @@ pub trait CopyOps<T> : TooDeeOpsMut<T> {
             },
         }
     }
+
+    /// Copies the `src` area (top-left, bottom-right) to a destination area. Unlike `copy_within()`,
+    /// an error is returned (and nothing is copied) if the `src` area is invalid or out of bounds,
+    /// or there's insufficient room to copy all of `src` to `dest`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut,CopyOps,TooDeeError};
+    /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// toodee.view_mut((0, 0), (5, 1)).fill(42);
+    /// assert_eq!(toodee.try_copy_within(((0, 0), (5, 1)), (0, 1)), Ok(()));
+    /// assert_eq!(toodee[(3,1)], 42);
+    /// assert_eq!(toodee.try_copy_within(((0, 0), (5, 1)), (6, 1)), Err(TooDeeError::OutOfBounds));
+    /// ```
+    fn try_copy_within(&mut self, src: (Coordinate, Coordinate), dest: Coordinate) -> Result<(), TooDeeError>
+    where T : Copy {
+        let (top_left, bottom_right) = src;
+        let (num_cols, num_rows) = self.size();
+        let fits = |start: usize, end: usize, dest: usize, len: usize| {
+            start <= end && end <= len && dest.checked_add(end - start).is_some_and(|dest_end| dest_end <= len)
+        };
+        if fits(top_left.0, bottom_right.0, dest.0, num_cols) && fits(top_left.1, bottom_right.1, dest.1, num_rows) {
+            self.copy_within(src, dest);
+            Ok(())
+        } else {
+            Err(TooDeeError::OutOfBounds)
+        }
+    }
     
 }
 
@@ -159,7 +213,7 @@ impl<T> CopyOps<T> for TooDee<T> {
     }
     
     fn copy_from_toodee(&mut self, src: &impl TooDeeOps<T>) where T : Copy {
-        assert_eq!(self.size(), src.size());
+        assert_same_dims(self, src);
         let num_cols = self.num_cols();
         let mut v = self.data_mut();
         for r in src.rows() {
@@ -170,7 +224,7 @@ impl<T> CopyOps<T> for TooDee<T> {
     }
 
     fn clone_from_toodee(&mut self, src: &impl TooDeeOps<T>) where T : Clone {
-        assert_eq!(self.size(), src.size());
+        assert_same_dims(self, src);
         let num_cols = self.num_cols();
         let mut v = self.data_mut();
         for r in src.rows() {
@@ -0,0 +1,49 @@
+#![forbid(unsafe_code)]
+
+use crate::ops::*;
+
+/// Lookup table for the (reflected) CRC-32 polynomial used by zlib, PNG, etc.
+const CRC32_TABLE : [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Updates a (pre-conditioned) CRC-32 value with more data.
+fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    data.iter().fold(crc, |crc, &b| CRC32_TABLE[((crc ^ u32::from(b)) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+/// Provides checksums over the cells of byte arrays, e.g., for integrity checking.
+pub trait CrcOps : TooDeeOps<u8> {
+
+    /// Computes the CRC-32 (as used by zlib, PNG, etc.) of the cells in row-major order. Only the
+    /// cells within the area contribute, so a view has the same checksum as a `TooDee` with the
+    /// same contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,CrcOps};
+    /// let toodee = TooDee::from_vec(3, 3, b"123456789".to_vec());
+    /// assert_eq!(toodee.crc32(), 0xCBF4_3926);
+    /// ```
+    fn crc32(&self) -> u32 {
+        // each row is contiguous, so it can be processed as a single slice
+        !self.rows().fold(!0, crc32_update)
+    }
+}
+
+impl<O> CrcOps for O where O : TooDeeOps<u8> {}
@@ -0,0 +1,63 @@
+use core::fmt;
+use core::fmt::{ Display, Formatter };
+
+/// The error type for fallible `TooDee` operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TooDeeError {
+    /// The length of the provided data does not match the array dimensions.
+    LengthMismatch {
+        /// The length required by the array dimensions.
+        expected: usize,
+        /// The length of the provided data.
+        actual: usize,
+    },
+    /// `num_cols * num_rows` overflows a `usize`.
+    DimensionOverflow,
+    /// One of the dimensions is zero but the other is non-zero. Empty arrays must have no dimensions.
+    InconsistentZeroDimension,
+    /// A coordinate or region lies (at least partially) outside of the area.
+    OutOfBounds,
+    /// Encoded data, e.g., a PPM image, is malformed or uses an unsupported variant of the format.
+    InvalidFormat,
+    /// The dimensions of an array don't match the dimensions required, e.g., by the other layers of a `GridStack`.
+    DimensionMismatch {
+        /// The required `(num_cols, num_rows)`.
+        expected: (usize, usize),
+        /// The `(num_cols, num_rows)` of the provided array.
+        actual: (usize, usize),
+    },
+    /// A field of delimiter-separated text, e.g., the input to `from_csv()`, could not be parsed.
+    ParseError {
+        /// The zero-based line of the field.
+        row: usize,
+        /// The zero-based position of the field within its line.
+        col: usize,
+    },
+}
+
+impl Display for TooDeeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TooDeeError::LengthMismatch { expected, actual } => write!(f, "length mismatch: expected {}, found {}", expected, actual),
+            TooDeeError::DimensionOverflow => write!(f, "num_cols * num_rows overflows usize"),
+            TooDeeError::InconsistentZeroDimension => write!(f, "one dimension is zero but the other is non-zero"),
+            TooDeeError::OutOfBounds => write!(f, "coordinate or region is out of bounds"),
+            TooDeeError::InvalidFormat => write!(f, "invalid or unsupported data format"),
+            TooDeeError::DimensionMismatch { expected, actual } => write!(f, "dimension mismatch: expected {}x{}, found {}x{}",
+                expected.0, expected.1, actual.0, actual.1),
+            TooDeeError::ParseError { row, col } => write!(f, "parse error at row {}, column {}", row, col),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TooDeeError {}
+
+/// Validates the array dimensions, returning the number of cells.
+pub(crate) fn checked_size(num_cols: usize, num_rows: usize) -> Result<usize, TooDeeError> {
+    if (num_cols == 0 || num_rows == 0) && num_cols != num_rows {
+        return Err(TooDeeError::InconsistentZeroDimension);
+    }
+    num_cols.checked_mul(num_rows).ok_or(TooDeeError::DimensionOverflow)
+}
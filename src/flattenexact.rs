@@ -26,6 +26,11 @@ where
     pub(super) fn new(iter: I) -> FlattenExact<I> {
         FlattenExact { iter, frontiter: None, backiter: None }
     }
+
+    /// Creates a `FlattenExact` that yields the items of `front` before those of `iter`.
+    pub(super) fn with_front(iter: I, front: <I::Item as IntoIterator>::IntoIter) -> FlattenExact<I> {
+        FlattenExact { iter, frontiter: Some(front), backiter: None }
+    }
 }
 
 impl<I> Iterator for FlattenExact<I>
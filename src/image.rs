@@ -0,0 +1,490 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use num_traits::Float;
+
+use crate::ops::*;
+use crate::toodee::*;
+use crate::view::*;
+use crate::error::*;
+
+/// Image-processing operations for boolean (binary image) arrays.
+impl TooDee<bool> {
+
+    /// Computes the Manhattan (L1) distance from each cell to the nearest `true` cell, using a
+    /// two-pass chamfer algorithm. Cells that are `true` have a distance of `0`. If there are no
+    /// `true` cells, every cell is set to `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 3, vec![
+    ///     false, false, false,
+    ///     false, true,  false,
+    ///     false, false, false,
+    /// ]);
+    /// let distances = toodee.distance_transform_l1();
+    /// assert_eq!(distances.data(), &[
+    ///     2, 1, 2,
+    ///     1, 0, 1,
+    ///     2, 1, 2,
+    /// ]);
+    /// ```
+    pub fn distance_transform_l1(&self) -> TooDee<u32> {
+        let num_cols = self.num_cols();
+        let num_rows = self.num_rows();
+        let mut result = TooDee::init(num_cols, num_rows, u32::MAX);
+        // forward pass: top-left to bottom-right
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                let mut d = if self[(col, row)] { 0 } else { u32::MAX };
+                if col > 0 {
+                    d = d.min(result[(col - 1, row)].saturating_add(1));
+                }
+                if row > 0 {
+                    d = d.min(result[(col, row - 1)].saturating_add(1));
+                }
+                result[(col, row)] = d;
+            }
+        }
+        // backward pass: bottom-right to top-left
+        for row in (0..num_rows).rev() {
+            for col in (0..num_cols).rev() {
+                let mut d = result[(col, row)];
+                if col + 1 < num_cols {
+                    d = d.min(result[(col + 1, row)].saturating_add(1));
+                }
+                if row + 1 < num_rows {
+                    d = d.min(result[(col, row + 1)].saturating_add(1));
+                }
+                result[(col, row)] = d;
+            }
+        }
+        result
+    }
+
+    /// Dilates the `true` regions using a square structuring element that extends `radius` cells in
+    /// each direction. Cells outside the array are treated as `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::init(5, 5, false);
+    /// toodee[(2, 2)] = true;
+    /// let dilated = toodee.dilate(1);
+    /// assert!(dilated[(1, 1)] && dilated[(3, 3)]);
+    /// assert!(!dilated[(0, 2)]);
+    /// ```
+    pub fn dilate(&self, radius: usize) -> TooDee<bool> {
+        self.morphology(radius, true)
+    }
+
+    /// Erodes the `true` regions using a square structuring element that extends `radius` cells in
+    /// each direction. Cells outside the array are treated as `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::init(5, 5, true);
+    /// let eroded = toodee.erode(1);
+    /// assert!(eroded[(2, 2)]);
+    /// assert!(!eroded[(0, 0)]);
+    /// ```
+    pub fn erode(&self, radius: usize) -> TooDee<bool> {
+        self.morphology(radius, false)
+    }
+
+    /// The square structuring element is separable, so apply a horizontal pass followed by a vertical pass.
+    fn morphology(&self, radius: usize, dilate: bool) -> TooDee<bool> {
+        let horizontal = window_pass(self, radius, dilate, true);
+        window_pass(&horizontal, radius, dilate, false)
+    }
+}
+
+/// Applies a one-dimensional max (dilate) or min (erode) filter along each row or column, using a sliding count
+/// of `true` cells within the window.
+fn window_pass(src: &TooDee<bool>, radius: usize, dilate: bool, horizontal: bool) -> TooDee<bool> {
+    let num_cols = src.num_cols();
+    let num_rows = src.num_rows();
+    let (len, lanes) = if horizontal { (num_cols, num_rows) } else { (num_rows, num_cols) };
+    let at = |lane: usize, i: usize| if horizontal { (i, lane) } else { (lane, i) };
+    let window = radius.saturating_mul(2).saturating_add(1);
+    let mut result = TooDee::init(num_cols, num_rows, false);
+    for lane in 0..lanes {
+        // number of `true` cells in the window [i - radius, i + radius]
+        let mut count = (0..len.min(radius)).filter(|&i| src[at(lane, i)]).count();
+        for i in 0..len {
+            if let Some(entering) = i.checked_add(radius).filter(|&e| e < len) {
+                count += src[at(lane, entering)] as usize;
+            }
+            if let Some(leaving) = i.checked_sub(radius + 1) {
+                count -= src[at(lane, leaving)] as usize;
+            }
+            result[at(lane, i)] = if dilate { count > 0 } else { count == window };
+        }
+    }
+    result
+}
+
+/// Image-processing operations for floating point arrays.
+impl<T: Float> TooDee<T> {
+
+    /// Samples the array at the fractional coordinate (`x`, `y`) using bilinear interpolation of the
+    /// four surrounding cells. Cell centres lie on integer coordinates, with `x` selecting the column
+    /// and `y` the row. Coordinates are clamped to the valid range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 2, vec![0.0f32, 1.0, 2.0, 3.0]);
+    /// assert_eq!(toodee.sample_bilinear(1.0, 0.0), 1.0);
+    /// assert_eq!(toodee.sample_bilinear(0.5, 0.5), 1.5);
+    /// ```
+    pub fn sample_bilinear(&self, x: f32, y: f32) -> T {
+        assert!(!self.is_empty());
+        let max_x = (self.num_cols() - 1) as f32;
+        let max_y = (self.num_rows() - 1) as f32;
+        // `max`/`min` also map NaN to the lower bound
+        let x = x.max(0.0).min(max_x);
+        let y = y.max(0.0).min(max_y);
+        let col0 = x as usize;
+        let row0 = y as usize;
+        let col1 = (col0 + 1).min(self.num_cols() - 1);
+        let row1 = (row0 + 1).min(self.num_rows() - 1);
+        let fx = T::from(x - col0 as f32).unwrap();
+        let fy = T::from(y - row0 as f32).unwrap();
+        let top = lerp(self[(col0, row0)], self[(col1, row0)], fx);
+        let bottom = lerp(self[(col0, row1)], self[(col1, row1)], fx);
+        lerp(top, bottom, fy)
+    }
+
+    /// Applies an affine transform, producing a new array with the given dimensions. The `matrix`
+    /// `[a, b, c, d, e, f]` maps each destination coordinate `(x, y)` back to the source coordinate
+    /// `(a * x + b * y + c, d * x + e * y + f)`, which is then sampled using `sample_bilinear()`.
+    /// Destination cells that map outside of the source area are set to `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 2, vec![1.0f32, 2.0, 3.0, 4.0]);
+    /// // shift one column to the right
+    /// let shifted = toodee.warp_affine([1.0, 0.0, -1.0, 0.0, 1.0, 0.0], 2, 2, 0.0);
+    /// assert_eq!(shifted.data(), &[0.0, 1.0, 0.0, 3.0]);
+    /// ```
+    pub fn warp_affine(&self, matrix: [f32; 6], out_cols: usize, out_rows: usize, fill: T) -> TooDee<T> {
+        let [a, b, c, d, e, f] = matrix;
+        let max_x = self.num_cols() as f32 - 1.0;
+        let max_y = self.num_rows() as f32 - 1.0;
+        let mut result = TooDee::init(out_cols, out_rows, fill);
+        for (y, row) in result.rows_mut().enumerate() {
+            let y = y as f32;
+            for (x, cell) in row.iter_mut().enumerate() {
+                let x = x as f32;
+                let src_x = a * x + b * y + c;
+                let src_y = d * x + e * y + f;
+                if (0.0..=max_x).contains(&src_x) && (0.0..=max_y).contains(&src_y) {
+                    *cell = self.sample_bilinear(src_x, src_y);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Image-processing operations for unsigned integer arrays.
+impl<T> TooDee<T> where T: Copy + Into<u64> + TryFrom<u64> {
+
+    /// Downsamples the array by an integer `factor`, e.g., to generate mipmaps. Each output cell is the
+    /// average (rounded to the nearest integer) of a `factor` x `factor` block of source cells, so the
+    /// result has the dimensions `(ceil(num_cols / factor), ceil(num_rows / factor))`. Partial blocks
+    /// at the right and bottom edges are averaged over the cells they actually contain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![0u16, 10, 50, 20, 30, 60]);
+    /// let small = toodee.downsample_average(2);
+    /// assert_eq!(small.size(), (2, 1));
+    /// assert_eq!(small.data(), &[15, 55]);
+    /// ```
+    pub fn downsample_average(&self, factor: usize) -> TooDee<T> {
+        assert!(factor > 0, "factor must be non-zero");
+        let data = self.tiles(factor, factor).map(|block| {
+            let count = block.num_cols() as u128 * block.num_rows() as u128;
+            let sum : u128 = block.cells().map(|&v| Into::<u64>::into(v) as u128).sum();
+            let average = ((sum + count / 2) / count) as u64;
+            T::try_from(average).unwrap_or_else(|_| unreachable!("an average can't exceed the largest value"))
+        }).collect();
+        TooDee::from_vec(self.num_cols().div_ceil(factor), self.num_rows().div_ceil(factor), data)
+    }
+}
+
+/// Image-processing operations for byte (greyscale) arrays.
+impl TooDee<u8> {
+
+    /// Replaces each cell `v` with `lut[v]`, e.g., to apply gamma correction or a threshold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(3, 1, vec![0u8, 100, 255]);
+    /// let invert : [u8; 256] = core::array::from_fn(|v| 255 - v as u8);
+    /// toodee.apply_lut(&invert);
+    /// assert_eq!(toodee.data(), &[255, 155, 0]);
+    /// ```
+    pub fn apply_lut(&mut self, lut: &[u8; 256]) {
+        // the backing slice is contiguous, so no per-row bookkeeping is needed
+        for v in self.data_mut() {
+            *v = lut[*v as usize];
+        }
+    }
+
+    /// Replaces each `block` x `block` region with its average value (rounded to the nearest integer),
+    /// giving a mosaic effect. Partial blocks at the right and bottom edges are averaged over the
+    /// cells they actually contain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![0u8, 10, 50, 20, 30, 60]);
+    /// toodee.pixelate(2);
+    /// assert_eq!(toodee.data(), &[15, 15, 55, 15, 15, 55]);
+    /// ```
+    pub fn pixelate(&mut self, block: usize) {
+        assert!(block > 0, "block size must be non-zero");
+        let averages = self.downsample_average(block);
+        // both visit the blocks in row-major order
+        for (mut region, &average) in self.tiles_mut(block, block).zip(averages.cells()) {
+            region.fill(average);
+        }
+    }
+
+    /// Computes the Sobel gradient magnitude at each pixel, i.e., `sqrt(gx^2 + gy^2)` where `gx` and
+    /// `gy` are the responses to the standard 3x3 horizontal and vertical Sobel kernels. The result
+    /// is rounded to the nearest integer. Pixels beyond the edges are treated as copies of the
+    /// nearest edge pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_fn(4, 3, |(col, _)| if col < 2 { 0u8 } else { 255 });
+    /// let edges = toodee.sobel_magnitude();
+    /// assert_eq!(edges[0], [0, 1020, 1020, 0]);
+    /// ```
+    pub fn sobel_magnitude(&self) -> TooDee<u16> {
+        let (num_cols, num_rows) = self.size();
+        TooDee::from_fn(num_cols, num_rows, |(col, row)| {
+            // clamp the neighbouring coordinates to the edges
+            let (left, right) = (col.saturating_sub(1), (col + 1).min(num_cols - 1));
+            let (up, down) = (row.saturating_sub(1), (row + 1).min(num_rows - 1));
+            let p = |c: usize, r: usize| self[(c, r)] as i32;
+            let gx = (p(right, up) + 2 * p(right, row) + p(right, down)) - (p(left, up) + 2 * p(left, row) + p(left, down));
+            let gy = (p(left, down) + 2 * p(col, down) + p(right, down)) - (p(left, up) + 2 * p(col, up) + p(right, up));
+            // at most sqrt(2) * 1020, so the result always fits
+            Float::round(Float::sqrt((gx * gx + gy * gy) as f64)) as u16
+        })
+    }
+}
+
+/// Image operations for RGB arrays.
+impl TooDee<[u8; 3]> {
+
+    /// Encodes the array as a binary (P6) PPM image, which is handy for quick debugging dumps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 1, vec![[255, 0, 0], [0, 0, 255]]);
+    /// assert_eq!(toodee.to_ppm(), b"P6\n2 1\n255\n\xff\0\0\0\0\xff");
+    /// ```
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.num_cols(), self.num_rows()).into_bytes();
+        ppm.extend_from_slice(self.data().as_flattened());
+        ppm
+    }
+
+    /// Converts the RGB array to grayscale using the standard luma weights (0.299, 0.587, 0.114).
+    /// The weights are approximated in 16-bit fixed point, and the result is rounded to the nearest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 1, vec![[255, 255, 255], [0, 255, 0]]);
+    /// assert_eq!(toodee.to_grayscale().data(), &[255, 150]);
+    /// ```
+    pub fn to_grayscale(&self) -> TooDee<u8> {
+        let data = self.rows().flat_map(|row| row.iter().map(|&rgb| luma(rgb))).collect();
+        TooDee::from_vec(self.num_cols(), self.num_rows(), data)
+    }
+}
+
+/// Computes the luma of an RGB pixel. The fixed-point weights sum to `1 << 16`, so the result can't exceed 255.
+fn luma([r, g, b]: [u8; 3]) -> u8 {
+    let weighted = 19595 * r as u32 + 38470 * g as u32 + 7471 * b as u32;
+    ((weighted + (1 << 15)) >> 16) as u8
+}
+
+/// Image operations for RGBA arrays.
+impl TooDee<[u8; 4]> {
+
+    /// Composites `top` over this array using standard (non-premultiplied) source-over alpha
+    /// blending, i.e., `top` is treated as the upper layer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `top` has different dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut bottom = TooDee::init(2, 1, [0u8, 0, 255, 255]);
+    /// let top = TooDee::from_vec(2, 1, vec![[255, 0, 0, 255], [255, 0, 0, 0]]);
+    /// bottom.blend_over(&top);
+    /// assert_eq!(bottom.data(), &[[255, 0, 0, 255], [0, 0, 255, 255]]);
+    /// ```
+    pub fn blend_over(&mut self, top: &impl TooDeeOps<[u8; 4]>) {
+        assert_same_dims(self, top);
+        for (dst, src) in self.rows_mut().zip(top.rows()) {
+            for (d, s) in dst.iter_mut().zip(src) {
+                *d = blend_pixel_over(*s, *d);
+            }
+        }
+    }
+}
+
+/// Composites the `top` pixel over the `bottom` pixel, rounding to the nearest value.
+fn blend_pixel_over(top: [u8; 4], bottom: [u8; 4]) -> [u8; 4] {
+    let top_alpha = u32::from(top[3]);
+    let bottom_alpha = u32::from(bottom[3]) * (255 - top_alpha);
+    // the output alpha, scaled by 255
+    let alpha = top_alpha * 255 + bottom_alpha;
+    if alpha == 0 {
+        return [0; 4];
+    }
+    let mut out = [0u8; 4];
+    for i in 0..3 {
+        let c = u32::from(top[i]) * top_alpha * 255 + u32::from(bottom[i]) * bottom_alpha;
+        out[i] = ((c + alpha / 2) / alpha) as u8;
+    }
+    out[3] = ((alpha + 127) / 255) as u8;
+    out
+}
+
+/// Decodes a binary (P6) PPM image, such as one produced by `to_ppm()`, into an RGB array. Only
+/// a maximum value of 255 (i.e., one byte per channel) is supported.
+///
+/// # Errors
+///
+/// Returns `TooDeeError::InvalidFormat` if the header is malformed or unsupported, and
+/// `TooDeeError::LengthMismatch` if the pixel data doesn't match the dimensions in the header.
+/// The dimensions themselves are validated as per `TooDee::try_init()`.
+///
+/// # Examples
+///
+/// ```
+/// use toodee::{TooDee,TooDeeOps,from_ppm};
+/// let toodee = from_ppm(b"P6\n2 1\n255\n\xff\0\0\0\0\xff").unwrap();
+/// assert_eq!(toodee.data(), &[[255, 0, 0], [0, 0, 255]]);
+/// ```
+pub fn from_ppm(bytes: &[u8]) -> Result<TooDee<[u8; 3]>, TooDeeError> {
+    let (magic, rest) = ppm_token(bytes)?;
+    if magic != b"P6" {
+        return Err(TooDeeError::InvalidFormat);
+    }
+    let (num_cols, rest) = ppm_number(rest)?;
+    let (num_rows, rest) = ppm_number(rest)?;
+    let (max_value, rest) = ppm_number(rest)?;
+    if max_value != 255 {
+        return Err(TooDeeError::InvalidFormat);
+    }
+    // a single whitespace character separates the header from the pixel data
+    let body = match rest.split_first() {
+        Some((c, body)) if c.is_ascii_whitespace() => body,
+        _ => return Err(TooDeeError::InvalidFormat),
+    };
+    let expected = checked_size(num_cols, num_rows)?.checked_mul(3).ok_or(TooDeeError::DimensionOverflow)?;
+    if body.len() != expected {
+        return Err(TooDeeError::LengthMismatch { expected, actual: body.len() });
+    }
+    let (pixels, _) = body.as_chunks::<3>();
+    Ok(TooDee::from_vec(num_cols, num_rows, pixels.to_vec()))
+}
+
+/// Splits the next token from a PPM header, skipping any leading whitespace and comments.
+fn ppm_token(bytes: &[u8]) -> Result<(&[u8], &[u8]), TooDeeError> {
+    let mut rest = bytes;
+    loop {
+        match rest.first() {
+            Some(c) if c.is_ascii_whitespace() => rest = &rest[1..],
+            Some(b'#') => {
+                let end = rest.iter().position(|&c| c == b'\n').ok_or(TooDeeError::InvalidFormat)?;
+                rest = &rest[end..];
+            },
+            Some(_) => break,
+            None => return Err(TooDeeError::InvalidFormat),
+        }
+    }
+    let end = rest.iter().position(|&c| c.is_ascii_whitespace() || c == b'#').unwrap_or(rest.len());
+    Ok(rest.split_at(end))
+}
+
+/// Splits the next token from a PPM header, and parses it as a decimal number.
+fn ppm_number(bytes: &[u8]) -> Result<(usize, &[u8]), TooDeeError> {
+    let (token, rest) = ppm_token(bytes)?;
+    if !token.iter().all(u8::is_ascii_digit) {
+        return Err(TooDeeError::InvalidFormat);
+    }
+    // the token is ASCII digits only, so it is valid UTF-8
+    let number = core::str::from_utf8(token).ok().and_then(|s| s.parse().ok()).ok_or(TooDeeError::InvalidFormat)?;
+    Ok((number, rest))
+}
+
+/// Image-processing operations for byte (greyscale) views.
+impl TooDeeViewMut<'_, u8> {
+
+    /// Replaces each cell `v` within the view with `lut[v]`, e.g., to apply gamma correction or
+    /// a threshold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::from_vec(3, 1, vec![0u8, 100, 255]);
+    /// let threshold : [u8; 256] = core::array::from_fn(|v| if v < 128 { 0 } else { 255 });
+    /// toodee.view_mut((1, 0), (3, 1)).apply_lut(&threshold);
+    /// assert_eq!(toodee.data(), &[0, 0, 255]);
+    /// ```
+    pub fn apply_lut(&mut self, lut: &[u8; 256]) {
+        for v in self.cells_mut() {
+            *v = lut[*v as usize];
+        }
+    }
+}
+
+fn lerp<T: Float>(a: T, b: T, t: T) -> T {
+    a + (b - a) * t
+}
@@ -1,5 +1,14 @@
-use core::mem;
+use core::fmt;
+use core::fmt::{Formatter, Debug};
+use core::iter::Enumerate;
+use core::marker::PhantomData;
 use core::ops::{Index, IndexMut};
+use core::ptr::NonNull;
+use core::slice;
+
+use crate::ops::{Coordinate, TooDeeOps};
+use crate::view::{TooDeeView, TooDeeViewMut};
+use crate::flattenexact::FlattenExact;
 
 /// An `Iterator` that knows how many columns it emits per row.
 pub trait TooDeeIterator : Iterator {
@@ -8,14 +17,63 @@ pub trait TooDeeIterator : Iterator {
 }
 
 /// An `Iterator` over each row of a `TooDee[View]`, where each row is represented as a slice.
-#[derive(Debug)]
 pub struct Rows<'a, T> {
-    /// This reference contains row data at each end. When iterating in either direction the row will
-    /// be pulled off the end then `skip_cols` elements will be skipped in preparation for reading the
-    /// next row.
-    pub(super) v: &'a [T],
+    /// Points to the start of the first remaining row. Rows are `stride` elements apart, and
+    /// the elements between rows are never accessed, so they may belong to another view.
+    pub(super) ptr: NonNull<T>,
+    pub(super) rows: usize,
     pub(super) cols: usize,
-    pub(super) skip_cols: usize,
+    pub(super) stride: usize,
+    pub(super) _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Rows<'a, T> {
+    /// Creates a new `Rows` iterator.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `rows` rows, each `cols` elements long and `stride`
+    /// elements apart, for the lifetime `'a`.
+    #[inline]
+    pub(super) unsafe fn new(ptr: *const T, rows: usize, cols: usize, stride: usize) -> Rows<'a, T> {
+        Rows {
+            ptr: NonNull::new_unchecked(ptr as *mut T),
+            rows: if cols == 0 { 0 } else { rows },
+            cols,
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Flattens the remaining rows into cells. Contiguous rows are traversed as a single slice,
+    /// which avoids the per-row bookkeeping of `FlattenExact`.
+    pub(super) fn into_cells(self) -> FlattenExact<Rows<'a, T>> {
+        if self.rows > 1 && self.stride == self.cols {
+            // the rows are contiguous, so all cells lie within a single range of memory
+            let cells = unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.rows * self.cols) };
+            FlattenExact::with_front(Rows { rows: 0, ..self }, cells.iter())
+        } else {
+            FlattenExact::new(self)
+        }
+    }
+
+    /// Returns the main diagonal of the remaining rows, i.e., `(0, 0)`, `(1, 1)`, and so on.
+    pub(super) fn into_diagonal(self) -> Diagonal<'a, T> {
+        // each step down and to the right moves `stride + 1` elements
+        unsafe { Col::new(self.ptr.as_ptr(), self.rows.min(self.cols), self.stride + 1) }
+    }
+}
+
+// NonNull is !Sync, so we need to implement Sync manually
+unsafe impl<T: Sync> Sync for Rows<'_, T> {}
+
+// NonNull is !Send, so we need to implement Send manually
+unsafe impl<T: Sync> Send for Rows<'_, T> {}
+
+impl<T> Clone for Rows<'_, T> {
+    fn clone(&self) -> Self {
+        Rows { ..*self }
+    }
 }
 
 impl<'a, T> Iterator for Rows<'a, T> {
@@ -24,88 +82,70 @@ impl<'a, T> Iterator for Rows<'a, T> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.v.is_empty() {
+        if self.rows == 0 {
             None
         } else {
-            let (fst, snd) = self.v.split_at(self.cols);
-            if snd.is_empty() {
-                self.v = &[];
-            } else {
-                // snd must contain at least one row, so no check required
-                unsafe {
-                    self.v = snd.get_unchecked(self.skip_cols..);
-                }
+            let row = unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.cols) };
+            self.rows -= 1;
+            // only advance when another row exists, so the pointer never leaves the allocation
+            if self.rows > 0 {
+                self.ptr = unsafe { self.ptr.add(self.stride) };
             }
-            Some(fst)
+            Some(row)
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.cols == 0 {
-            return (0, Some(0));
-        }
-        let len = self.v.len();
-        let denom = self.cols + self.skip_cols;
-        let n = len / denom + (len % denom) / self.cols;
-        (n, Some(n))
+        (self.rows, Some(self.rows))
     }
 
     #[inline]
     fn count(self) -> usize {
         self.len()
     }
-    
+
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        
-        let (start, overflow) = n.overflowing_mul(self.cols + self.skip_cols);
-        if start >= self.v.len() || overflow {
-            self.v = &[];
+        if n >= self.rows {
+            self.rows = 0;
+            None
         } else {
-            let (_, snd) = self.v.split_at(start);
-            self.v = snd;
+            // n < rows, so the new row is within bounds
+            self.ptr = unsafe { self.ptr.add(n * self.stride) };
+            self.rows -= n;
+            self.next()
         }
-        self.next()
     }
 
     #[inline]
     fn last(mut self) -> Option<Self::Item> {
         self.next_back()
-    }    
+    }
 }
 
 impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.v.is_empty() {
+        if self.rows == 0 {
             None
         } else {
-            let (fst, snd) = self.v.split_at(self.v.len() - self.cols);
-            if fst.is_empty() {
-                self.v = &[];
-            } else {
-                // skip_cols will be <= fst.len(), so no check required
-                unsafe {
-                    self.v = fst.get_unchecked(..fst.len() - self.skip_cols);
-                }
+            self.rows -= 1;
+            unsafe {
+                Some(slice::from_raw_parts(self.ptr.as_ptr().add(self.rows * self.stride), self.cols))
             }
-            Some(snd)
         }
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (adj, overflow) = n.overflowing_mul(self.cols + self.skip_cols);
-        if adj >= self.v.len() || overflow {
-            self.v = &[];
+        if n >= self.rows {
+            self.rows = 0;
+            None
         } else {
-            // adj < fst.len(), so no check required
-            unsafe {
-                self.v = self.v.get_unchecked(..self.v.len() - adj);
-            }
+            self.rows -= n;
+            self.next_back()
         }
-        self.next_back()
     }
 }
 
@@ -117,110 +157,137 @@ impl<T> TooDeeIterator for Rows<'_, T> {
     }
 }
 
+impl<T> Debug for Rows<'_, T> where T: Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
 /// A mutable Iterator over each row of a `TooDee[ViewMut]`, where each row is represented as a slice.
-#[derive(Debug)]
 pub struct RowsMut<'a, T> {
-    /// This reference contains row data at each end. When iterating in either direction the row will
-    /// be pulled off the end then `skip_cols` elements will be skipped in preparation for reading the
-    /// next row.
-    pub(super) v: &'a mut [T],
+    /// Points to the start of the first remaining row. Rows are `stride` elements apart, and
+    /// the elements between rows are never accessed, so they may belong to another view.
+    pub(super) ptr: NonNull<T>,
+    pub(super) rows: usize,
     pub(super) cols: usize,
-    pub(super) skip_cols: usize,
+    pub(super) stride: usize,
+    pub(super) _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> RowsMut<'a, T> {
+    /// Creates a new `RowsMut` iterator.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `rows` rows, each `cols` elements long and
+    /// `stride` elements apart, and those elements must not be accessed by anything else for
+    /// the lifetime `'a`.
+    #[inline]
+    pub(super) unsafe fn new(ptr: *mut T, rows: usize, cols: usize, stride: usize) -> RowsMut<'a, T> {
+        RowsMut {
+            ptr: NonNull::new_unchecked(ptr),
+            rows: if cols == 0 { 0 } else { rows },
+            cols,
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Flattens the remaining rows into cells. Contiguous rows are traversed as a single slice,
+    /// which avoids the per-row bookkeeping of `FlattenExact`.
+    pub(super) fn into_cells(self) -> FlattenExact<RowsMut<'a, T>> {
+        if self.rows > 1 && self.stride == self.cols {
+            // the rows are contiguous, so all cells lie within a single range of memory
+            let cells = unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.rows * self.cols) };
+            FlattenExact::with_front(RowsMut { rows: 0, ..self }, cells.iter_mut())
+        } else {
+            FlattenExact::new(self)
+        }
+    }
+
+    /// Returns the main diagonal of the remaining rows, i.e., `(0, 0)`, `(1, 1)`, and so on.
+    pub(super) fn into_diagonal(self) -> DiagonalMut<'a, T> {
+        // each step down and to the right moves `stride + 1` elements
+        unsafe { ColMut::new(self.ptr.as_ptr(), self.rows.min(self.cols), self.stride + 1) }
+    }
 }
 
+// NonNull is !Sync, so we need to implement Sync manually
+unsafe impl<T: Sync> Sync for RowsMut<'_, T> {}
+
+// NonNull is !Send, so we need to implement Send manually
+unsafe impl<T: Send> Send for RowsMut<'_, T> {}
+
 impl<'a, T> Iterator for RowsMut<'a, T> {
 
     type Item = &'a mut [T];
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.v.is_empty() {
+        if self.rows == 0 {
             None
         } else {
-            let tmp = mem::take(&mut self.v);
-            let (head, tail) = tmp.split_at_mut(self.cols);
-            if tail.is_empty() {
-                self.v = &mut [];
-            } else {
-                // tail must contain at least one row, so no check required
-                unsafe {
-                    self.v = tail.get_unchecked_mut(self.skip_cols..);
-                }
+            let row = unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.cols) };
+            self.rows -= 1;
+            // only advance when another row exists, so the pointer never leaves the allocation
+            if self.rows > 0 {
+                self.ptr = unsafe { self.ptr.add(self.stride) };
             }
-            Some(head)
+            Some(row)
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.cols == 0 {
-            return (0, Some(0));
-        }
-        let len = self.v.len();
-        let denom = self.cols + self.skip_cols;
-        let n = len / denom + (len % denom) / self.cols;
-        (n, Some(n))
+        (self.rows, Some(self.rows))
     }
 
     #[inline]
     fn count(self) -> usize {
         self.len()
     }
-    
+
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (start, overflow) = n.overflowing_mul(self.cols + self.skip_cols);
-        if start >= self.v.len() || overflow {
-            self.v = &mut [];
+        if n >= self.rows {
+            self.rows = 0;
+            None
         } else {
-            let tmp = mem::take(&mut self.v);
-            let (_, snd) = tmp.split_at_mut(start);
-            self.v = snd;
+            // n < rows, so the new row is within bounds
+            self.ptr = unsafe { self.ptr.add(n * self.stride) };
+            self.rows -= n;
+            self.next()
         }
-        self.next()
     }
-    
+
     #[inline]
     fn last(mut self) -> Option<Self::Item> {
         self.next_back()
-    }    
+    }
 }
 
 impl<'a, T> DoubleEndedIterator for RowsMut<'a, T> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.v.is_empty() {
+        if self.rows == 0 {
             None
         } else {
-            let tmp = mem::take(&mut self.v);
-            let tmp_len = tmp.len();
-            let (fst, snd) = tmp.split_at_mut(tmp_len - self.cols);
-            if fst.is_empty() {
-                self.v = &mut [];
-            } else {
-                // fst must contain at least one row, so no check required
-                unsafe {
-                    self.v = fst.get_unchecked_mut(..tmp_len - self.cols - self.skip_cols);
-                }
+            self.rows -= 1;
+            unsafe {
+                Some(slice::from_raw_parts_mut(self.ptr.as_ptr().add(self.rows * self.stride), self.cols))
             }
-            Some(snd)
         }
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-
-        let (adj, overflow) = n.overflowing_mul(self.cols + self.skip_cols);
-        if adj >= self.v.len() || overflow {
-            self.v = &mut [];
+        if n >= self.rows {
+            self.rows = 0;
+            None
         } else {
-            let tmp = mem::take(&mut self.v);
-            // adj < self.v.len(), so no check required
-            unsafe {
-                self.v = tmp.get_unchecked_mut(..self.v.len() - adj);
-            }
+            self.rows -= n;
+            self.next_back()
         }
-        self.next_back()
     }
 }
 
@@ -232,17 +299,79 @@ impl<T> TooDeeIterator for RowsMut<'_, T> {
     }
 }
 
+impl<T> Debug for RowsMut<'_, T> where T: Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rows = unsafe { Rows::new(self.ptr.as_ptr(), self.rows, self.cols, self.stride) };
+        f.debug_list().entries(rows).finish()
+    }
+}
+
+/// An iterator over the main diagonal of a `TooDee[View]`. The diagonal is traversed
+/// in the same way as a column, just with a larger stride.
+pub type Diagonal<'a, T> = Col<'a, T>;
+
+/// A mutable iterator over the main diagonal of a `TooDee[ViewMut]`.
+pub type DiagonalMut<'a, T> = ColMut<'a, T>;
+
 /// An iterator over a single column.
-#[derive(Debug)]
 pub struct Col<'a, T> {
-    pub(super) v: &'a [T],
-    pub(super) skip: usize,
+    /// Points to the first remaining element. Elements are `stride` apart.
+    pub(super) ptr: NonNull<T>,
+    pub(super) len: usize,
+    pub(super) stride: usize,
+    pub(super) _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Col<'a, T> {
+    /// Creates a new `Col` iterator.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `len` elements, each `stride` elements apart, for the
+    /// lifetime `'a`.
+    #[inline]
+    pub(super) unsafe fn new(ptr: *const T, len: usize, stride: usize) -> Col<'a, T> {
+        Col {
+            ptr: NonNull::new_unchecked(ptr as *mut T),
+            len,
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements remaining in the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// let mut col = toodee.col(2);
+    /// assert_eq!(col.col_len(), 5);
+    /// col.next();
+    /// assert_eq!(col.col_len(), 4);
+    /// ```
+    pub fn col_len(&self) -> usize {
+        self.len
+    }
+}
+
+// NonNull is !Sync, so we need to implement Sync manually
+unsafe impl<T: Sync> Sync for Col<'_, T> {}
+
+// NonNull is !Send, so we need to implement Send manually
+unsafe impl<T: Sync> Send for Col<'_, T> {}
+
+impl<T> Clone for Col<'_, T> {
+    fn clone(&self) -> Self {
+        Col { ..*self }
+    }
 }
 
 impl<'a, T> Index<usize> for Col<'a, T> {
     type Output = T;
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
     /// let toodee : TooDee<u32> = TooDee::new(10, 5);
@@ -250,8 +379,10 @@ impl<'a, T> Index<usize> for Col<'a, T> {
     /// assert_eq!(col[3], 0);
     /// ```
     fn index(&self, idx: usize) -> &Self::Output {
-        let pos = idx * (1 + self.skip);
-        &self.v[pos]
+        assert!(idx < self.len);
+        unsafe {
+            &*self.ptr.as_ptr().add(idx * self.stride)
+        }
     }
 }
 
@@ -261,45 +392,40 @@ impl<'a, T> Iterator for Col<'a, T> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some((fst, snd)) = self.v.split_first() {
-            if snd.is_empty() {
-                self.v = &[];
-            } else {
-                // snd must contain at least one row, so we don't need a bounds check
-                unsafe {
-                    self.v = snd.get_unchecked(self.skip..);
-                }
-            }
-            Some(fst)
-        } else {
+        if self.len == 0 {
             None
+        } else {
+            let item = unsafe { &*self.ptr.as_ptr() };
+            self.len -= 1;
+            // only advance when another element exists, so the pointer never leaves the allocation
+            if self.len > 0 {
+                self.ptr = unsafe { self.ptr.add(self.stride) };
+            }
+            Some(item)
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.v.len();
-        let denom = 1 + self.skip;
-        let n = len / denom + (len % denom);
-        (n, Some(n))
+        (self.len, Some(self.len))
     }
 
     #[inline]
     fn count(self) -> usize {
         self.len()
     }
-    
+
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        
-        let (start, overflow) = n.overflowing_mul(1 + self.skip);
-        if start >= self.v.len() || overflow {
-            self.v = &[];
+        if n >= self.len {
+            self.len = 0;
+            None
         } else {
-            let (_, snd) = self.v.split_at(start);
-            self.v = snd;
+            // n < len, so the new element is within bounds
+            self.ptr = unsafe { self.ptr.add(n * self.stride) };
+            self.len -= n;
+            self.next()
         }
-        self.next()
     }
 
     #[inline]
@@ -311,50 +437,88 @@ impl<'a, T> Iterator for Col<'a, T> {
 impl<'a, T> DoubleEndedIterator for Col<'a, T> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some((last, fst)) = self.v.split_last() {
-            if fst.is_empty() {
-                self.v = &[];
-            } else {
-                // fst must contain at least one row, so we don't need a bounds check
-                unsafe {
-                    self.v = fst.get_unchecked(..fst.len() - self.skip);
-                }
-            }
-            Some(last)
-        } else {
+        if self.len == 0 {
             None
+        } else {
+            self.len -= 1;
+            unsafe {
+                Some(&*self.ptr.as_ptr().add(self.len * self.stride))
+            }
         }
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let (adj, overflow) = n.overflowing_mul(1 + self.skip);
-        if adj >= self.v.len() || overflow {
-            self.v = &[];
+        if n >= self.len {
+            self.len = 0;
+            None
         } else {
-            // adj < self.v.len(), so no check required
-            unsafe {
-                self.v = self.v.get_unchecked(..self.v.len() - adj);
-            }
+            self.len -= n;
+            self.next_back()
         }
-        self.next_back()
     }
 }
 
 impl<T> ExactSizeIterator for Col<'_, T> {}
 
+impl<T> Debug for Col<'_, T> where T: Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
 
 /// A mutable iterator over a single column.
-#[derive(Debug)]
 pub struct ColMut<'a, T> {
-    pub(super) v: &'a mut [T],
-    pub(super) skip: usize,
+    /// Points to the first remaining element. Elements are `stride` apart.
+    pub(super) ptr: NonNull<T>,
+    pub(super) len: usize,
+    pub(super) stride: usize,
+    pub(super) _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> ColMut<'a, T> {
+    /// Creates a new `ColMut` iterator.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `len` elements, each `stride` elements apart,
+    /// and those elements must not be accessed by anything else for the lifetime `'a`.
+    #[inline]
+    pub(super) unsafe fn new(ptr: *mut T, len: usize, stride: usize) -> ColMut<'a, T> {
+        ColMut {
+            ptr: NonNull::new_unchecked(ptr),
+            len,
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements remaining in the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// let mut col = toodee.col_mut(2);
+    /// col.next_back();
+    /// assert_eq!(col.col_len(), 4);
+    /// ```
+    pub fn col_len(&self) -> usize {
+        self.len
+    }
 }
 
+// NonNull is !Sync, so we need to implement Sync manually
+unsafe impl<T: Sync> Sync for ColMut<'_, T> {}
+
+// NonNull is !Send, so we need to implement Send manually
+unsafe impl<T: Send> Send for ColMut<'_, T> {}
+
 impl<'a, T> Index<usize> for ColMut<'a, T> {
     type Output = T;
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
     /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
@@ -362,15 +526,17 @@ impl<'a, T> Index<usize> for ColMut<'a, T> {
     /// assert_eq!(col[3], 0);
     /// ```
     fn index(&self, idx: usize) -> &Self::Output {
-        let pos = idx * (1 + self.skip);
-        &self.v[pos]
+        assert!(idx < self.len);
+        unsafe {
+            &*self.ptr.as_ptr().add(idx * self.stride)
+        }
     }
 }
 
 impl<'a, T> IndexMut<usize> for ColMut<'a, T> {
 
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
     /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
@@ -378,8 +544,10 @@ impl<'a, T> IndexMut<usize> for ColMut<'a, T> {
     /// col[3] = 42;
     /// ```
     fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        let pos = idx * (1 + self.skip);
-        &mut self.v[pos]
+        assert!(idx < self.len);
+        unsafe {
+            &mut *self.ptr.as_ptr().add(idx * self.stride)
+        }
     }
 }
 
@@ -389,90 +557,883 @@ impl<'a, T> Iterator for ColMut<'a, T> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let tmp = mem::take(&mut self.v);
-        if let Some((fst, snd)) = tmp.split_first_mut() {
-            if snd.is_empty() {
-                self.v = &mut [];
-            } else {
-                // snd must contain at least one row, so no check required
-                unsafe {
-                    self.v = snd.get_unchecked_mut(self.skip..);
-                }
-            }
-            Some(fst)
-        } else {
+        if self.len == 0 {
             None
+        } else {
+            let item = unsafe { &mut *self.ptr.as_ptr() };
+            self.len -= 1;
+            // only advance when another element exists, so the pointer never leaves the allocation
+            if self.len > 0 {
+                self.ptr = unsafe { self.ptr.add(self.stride) };
+            }
+            Some(item)
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.v.len();
-        let denom = 1 + self.skip;
-        let n = len / denom + (len % denom);
-        (n, Some(n))
+        (self.len, Some(self.len))
     }
 
     #[inline]
     fn count(self) -> usize {
         self.len()
     }
-    
+
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let (start, overflow) = n.overflowing_mul(1 + self.skip);
-        if start >= self.v.len() || overflow {
-            self.v = &mut [];
+        if n >= self.len {
+            self.len = 0;
+            None
         } else {
-            let tmp = mem::take(&mut self.v);
-            let (_, snd) = tmp.split_at_mut(start);
-            self.v = snd;
+            // n < len, so the new element is within bounds
+            self.ptr = unsafe { self.ptr.add(n * self.stride) };
+            self.len -= n;
+            self.next()
         }
-        self.next()
     }
-    
+
     #[inline]
     fn last(mut self) -> Option<Self::Item> {
         self.next_back()
-    }    
+    }
 }
 
 impl<'a, T> DoubleEndedIterator for ColMut<'a, T> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        let tmp = mem::take(&mut self.v);
-        if let Some((last, fst)) = tmp.split_last_mut() {
-            if fst.is_empty() {
-                self.v = &mut [];
-            } else {
-                let new_len = fst.len() - self.skip;
-                // skip <= fst.len(), so no check required
-                unsafe {
-                    self.v = fst.get_unchecked_mut(..new_len);
-                }
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            unsafe {
+                Some(&mut *self.ptr.as_ptr().add(self.len * self.stride))
             }
-            Some(last)
+        }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            None
         } else {
+            self.len -= n;
+            self.next_back()
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for ColMut<'_, T> {}
+
+impl<T> Debug for ColMut<'_, T> where T: Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let col = unsafe { Col::new(self.ptr.as_ptr(), self.len, self.stride) };
+        f.debug_list().entries(col).finish()
+    }
+}
+
+/// An iterator over each column of a `TooDee[View]`, where each column is represented as a `Col`.
+pub struct Cols<'a, T> {
+    rows: Rows<'a, T>,
+    /// The next column from the front.
+    front: usize,
+    /// One past the next column from the back.
+    back: usize,
+}
+
+impl<'a, T> Cols<'a, T> {
+    pub(super) fn new(rows: Rows<'a, T>) -> Cols<'a, T> {
+        let back = if rows.rows == 0 { 0 } else { rows.cols };
+        Cols { rows, front: 0, back }
+    }
+
+    fn col(&self, col: usize) -> Col<'a, T> {
+        // col < cols, so the column lies within the area
+        unsafe {
+            Col::new(self.rows.ptr.as_ptr().add(col), self.rows.rows, self.rows.stride)
+        }
+    }
+}
+
+impl<T> Clone for Cols<'_, T> {
+    fn clone(&self) -> Self {
+        Cols { rows: self.rows.clone(), ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Cols<'a, T> {
+
+    type Item = Col<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
             None
+        } else {
+            self.front += 1;
+            Some(self.col(self.front - 1))
         }
     }
 
     #[inline]
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Cols<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.col(self.back))
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Cols<'_, T> {}
+
+impl<T> Debug for Cols<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cols").field("len", &self.len()).finish()
+    }
+}
+
+/// A mutable iterator over each column of a `TooDee[ViewMut]`, where each column is represented as a `ColMut`.
+pub struct ColsMut<'a, T> {
+    rows: RowsMut<'a, T>,
+    /// The next column from the front.
+    front: usize,
+    /// One past the next column from the back.
+    back: usize,
+}
+
+impl<'a, T> ColsMut<'a, T> {
+    pub(super) fn new(rows: RowsMut<'a, T>) -> ColsMut<'a, T> {
+        let back = if rows.rows == 0 { 0 } else { rows.cols };
+        ColsMut { rows, front: 0, back }
+    }
+
+    fn col(&self, col: usize) -> ColMut<'a, T> {
+        // col < cols, so the column lies within the area, and each column is only yielded once
+        unsafe {
+            ColMut::new(self.rows.ptr.as_ptr().add(col), self.rows.rows, self.rows.stride)
+        }
+    }
+}
+
+impl<'a, T> Iterator for ColsMut<'a, T> {
 
-        let (adj, overflow) = n.overflowing_mul(1 + self.skip);
-        if adj >= self.v.len() || overflow {
-            self.v = &mut [];
+    type Item = ColMut<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
         } else {
-            let tmp = mem::take(&mut self.v);
-            // adj <= self.v.len(), so no check required
-            unsafe {
-                self.v = tmp.get_unchecked_mut(..self.v.len() - adj);
-            }
+            self.front += 1;
+            Some(self.col(self.front - 1))
         }
-        self.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
     }
 }
 
-impl<T> ExactSizeIterator for ColMut<'_, T> {}
+impl<T> DoubleEndedIterator for ColsMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.col(self.back))
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for ColsMut<'_, T> {}
+
+impl<T> Debug for ColsMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColsMut").field("len", &self.len()).finish()
+    }
+}
+
+/// An iterator over each cell of a `TooDee[View]`, along with the cell's coordinate.
+pub struct IndexedCells<'a, T> {
+    iter: Enumerate<FlattenExact<Rows<'a, T>>>,
+    num_cols: usize,
+    /// Added to each coordinate, e.g., to convert view coordinates to parent coordinates.
+    origin: Coordinate,
+}
+
+impl<'a, T> IndexedCells<'a, T> {
+    pub(super) fn new(cells: FlattenExact<Rows<'a, T>>, origin: Coordinate) -> IndexedCells<'a, T> {
+        IndexedCells {
+            num_cols: cells.num_cols(),
+            iter: cells.enumerate(),
+            origin,
+        }
+    }
+
+    #[inline]
+    fn coord(&self, index: usize) -> Coordinate {
+        (self.origin.0 + index % self.num_cols, self.origin.1 + index / self.num_cols)
+    }
+}
+
+impl<'a, T> Iterator for IndexedCells<'a, T> {
+
+    type Item = (Coordinate, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, v)| (self.coord(i), v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IndexedCells<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(i, v)| (self.coord(i), v))
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedCells<'_, T> {}
+
+impl<T> Debug for IndexedCells<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexedCells").field("len", &self.len()).field("origin", &self.origin).finish()
+    }
+}
+
+/// A mutable iterator over each cell of a `TooDee[ViewMut]`, along with the cell's coordinate.
+pub struct IndexedCellsMut<'a, T> {
+    iter: Enumerate<FlattenExact<RowsMut<'a, T>>>,
+    num_cols: usize,
+}
+
+impl<'a, T> IndexedCellsMut<'a, T> {
+    pub(super) fn new(cells: FlattenExact<RowsMut<'a, T>>) -> IndexedCellsMut<'a, T> {
+        IndexedCellsMut {
+            num_cols: cells.num_cols(),
+            iter: cells.enumerate(),
+        }
+    }
+
+    #[inline]
+    fn coord(&self, index: usize) -> Coordinate {
+        (index % self.num_cols, index / self.num_cols)
+    }
+}
+
+impl<'a, T> Iterator for IndexedCellsMut<'a, T> {
+
+    type Item = (Coordinate, &'a mut T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, v)| (self.coord(i), v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
 
+impl<T> DoubleEndedIterator for IndexedCellsMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(i, v)| (self.coord(i), v))
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedCellsMut<'_, T> {}
+
+impl<T> Debug for IndexedCellsMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexedCellsMut").field("len", &self.len()).finish()
+    }
+}
+
+/// An iterator over runs of consecutive equal rows of a `TooDee[View]`. Each item is a row
+/// along with the number of times it is repeated.
+pub struct RowGroups<'a, T> {
+    rows: Rows<'a, T>,
+}
+
+impl<'a, T> RowGroups<'a, T> {
+    pub(super) fn new(rows: Rows<'a, T>) -> RowGroups<'a, T> {
+        RowGroups { rows }
+    }
+}
+
+impl<T> Clone for RowGroups<'_, T> {
+    fn clone(&self) -> Self {
+        RowGroups { rows: self.rows.clone() }
+    }
+}
+
+impl<'a, T> Iterator for RowGroups<'a, T> where T: PartialEq {
+
+    type Item = (&'a [T], usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next()?;
+        let mut count = 1;
+        // peek at the following rows via a (cheap) copy of the iterator
+        while self.rows.clone().next() == Some(row) {
+            self.rows.next();
+            count += 1;
+        }
+        Some((row, count))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rows.len();
+        (usize::from(len > 0), Some(len))
+    }
+}
+
+impl<T> Debug for RowGroups<'_, T> where T: Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowGroups").field("rows", &self.rows).finish()
+    }
+}
+
+/// An iterator over the anti-diagonals of a `TooDee[View]`, i.e., the cells where `col + row` is constant.
+/// Each diagonal is a `Col` that starts at its top-right cell and moves down and to the left.
+pub struct Diagonals<'a, T> {
+    rows: Rows<'a, T>,
+    /// The next diagonal from the front, i.e., `col + row`.
+    front: usize,
+    /// One past the next diagonal from the back.
+    back: usize,
+}
+
+impl<'a, T> Diagonals<'a, T> {
+    pub(super) fn new(rows: Rows<'a, T>) -> Diagonals<'a, T> {
+        let back = if rows.rows == 0 { 0 } else { rows.cols + rows.rows - 1 };
+        Diagonals { rows, front: 0, back }
+    }
+
+    fn diagonal(&self, k: usize) -> Col<'a, T> {
+        let col = k.min(self.rows.cols - 1);
+        let row = k - col;
+        let len = (col + 1).min(self.rows.rows - row);
+        // (col, row) is within the area, and each step down and to the left moves `stride - 1` elements
+        unsafe {
+            Col::new(self.rows.ptr.as_ptr().add(row * self.rows.stride + col), len, self.rows.stride - 1)
+        }
+    }
+}
+
+impl<T> Clone for Diagonals<'_, T> {
+    fn clone(&self) -> Self {
+        Diagonals { rows: self.rows.clone(), ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Diagonals<'a, T> {
+
+    type Item = Col<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+            Some(self.diagonal(self.front - 1))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Diagonals<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.diagonal(self.back))
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Diagonals<'_, T> {}
+
+impl<T> Debug for Diagonals<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Diagonals").field("len", &self.len()).finish()
+    }
+}
+
+/// An iterator over every (overlapping) `win_cols` x `win_rows` window of a `TooDee[View]`, in row-major
+/// order of each window's top-left corner. Each window is a `TooDeeView`, so no copying takes place.
+pub struct Windows2d<'a, T> {
+    view: TooDeeView<'a, T>,
+    win_cols: usize,
+    win_rows: usize,
+    /// The number of window positions in each row.
+    positions_per_row: usize,
+    /// The index of the next window position from the front.
+    front: usize,
+    /// One past the index of the next window position from the back.
+    back: usize,
+}
+
+impl<'a, T> Windows2d<'a, T> {
+    pub(super) fn new(view: TooDeeView<'a, T>, win_cols: usize, win_rows: usize) -> Windows2d<'a, T> {
+        assert!(win_cols > 0 && win_rows > 0, "window dimensions must be non-zero");
+        let (positions_per_row, back) = if win_cols <= view.num_cols() && win_rows <= view.num_rows() {
+            let positions_per_row = view.num_cols() - win_cols + 1;
+            (positions_per_row, positions_per_row * (view.num_rows() - win_rows + 1))
+        } else {
+            (0, 0)
+        };
+        Windows2d { view, win_cols, win_rows, positions_per_row, front: 0, back }
+    }
+
+    fn window(&self, index: usize) -> TooDeeView<'a, T> {
+        let col = index % self.positions_per_row;
+        let row = index / self.positions_per_row;
+        self.view.sub_view((col, row), (col + self.win_cols, row + self.win_rows))
+    }
+}
+
+impl<T> Clone for Windows2d<'_, T> {
+    fn clone(&self) -> Self {
+        Windows2d { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Windows2d<'a, T> {
+
+    type Item = TooDeeView<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+            Some(self.window(self.front - 1))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front += n.min(self.back - self.front);
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for Windows2d<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.window(self.back))
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Windows2d<'_, T> {}
+
+impl<T> Debug for Windows2d<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Windows2d").field("win_cols", &self.win_cols).field("win_rows", &self.win_rows).field("len", &self.len()).finish()
+    }
+}
+
+/// Tracks the remaining tiles of a `Tiles` or `TilesMut` iterator. Tiles are indexed in row-major order.
+#[derive(Clone, Copy)]
+struct TileCursor {
+    num_cols: usize,
+    num_rows: usize,
+    tile_cols: usize,
+    tile_rows: usize,
+    /// The number of tiles in each row of tiles.
+    tiles_per_row: usize,
+    /// The index of the next tile from the front.
+    front: usize,
+    /// One past the index of the next tile from the back.
+    back: usize,
+}
+
+impl TileCursor {
+    fn new(num_cols: usize, num_rows: usize, tile_cols: usize, tile_rows: usize) -> TileCursor {
+        assert!(tile_cols > 0 && tile_rows > 0, "tile dimensions must be non-zero");
+        let tiles_per_row = num_cols.div_ceil(tile_cols);
+        let back = tiles_per_row * num_rows.div_ceil(tile_rows);
+        TileCursor { num_cols, num_rows, tile_cols, tile_rows, tiles_per_row, front: 0, back }
+    }
+
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+
+    /// Returns the bounds of the tile at `index`, clipped to the area.
+    fn bounds(&self, index: usize) -> (Coordinate, Coordinate) {
+        let col = (index % self.tiles_per_row) * self.tile_cols;
+        let row = (index / self.tiles_per_row) * self.tile_rows;
+        ((col, row), (self.num_cols.min(col + self.tile_cols), self.num_rows.min(row + self.tile_rows)))
+    }
+
+    fn next(&mut self) -> Option<(Coordinate, Coordinate)> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+            Some(self.bounds(self.front - 1))
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<(Coordinate, Coordinate)> {
+        self.front += n.min(self.len());
+        self.next()
+    }
+
+    fn next_back(&mut self) -> Option<(Coordinate, Coordinate)> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.bounds(self.back))
+        }
+    }
+}
+
+/// An iterator over the non-overlapping `tile_cols` x `tile_rows` tiles of a `TooDee[View]`, in row-major
+/// order. Tiles at the right and bottom edges are clipped if the dimensions aren't exact multiples
+/// of the tile size.
+pub struct Tiles<'a, T> {
+    view: TooDeeView<'a, T>,
+    cursor: TileCursor,
+}
+
+impl<'a, T> Tiles<'a, T> {
+    pub(super) fn new(view: TooDeeView<'a, T>, tile_cols: usize, tile_rows: usize) -> Tiles<'a, T> {
+        let cursor = TileCursor::new(view.num_cols(), view.num_rows(), tile_cols, tile_rows);
+        Tiles { view, cursor }
+    }
+}
+
+impl<T> Clone for Tiles<'_, T> {
+    fn clone(&self) -> Self {
+        Tiles { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Tiles<'a, T> {
+
+    type Item = TooDeeView<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next().map(|(start, end)| self.view.sub_view(start, end))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.len(), Some(self.cursor.len()))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.nth(n).map(|(start, end)| self.view.sub_view(start, end))
+    }
+}
+
+impl<T> DoubleEndedIterator for Tiles<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cursor.next_back().map(|(start, end)| self.view.sub_view(start, end))
+    }
+}
+
+impl<T> ExactSizeIterator for Tiles<'_, T> {}
+
+impl<T> Debug for Tiles<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tiles").field("tile_cols", &self.cursor.tile_cols).field("tile_rows", &self.cursor.tile_rows).field("len", &self.len()).finish()
+    }
+}
+
+/// A mutable iterator over the non-overlapping `tile_cols` x `tile_rows` tiles of a `TooDee[ViewMut]`, in
+/// row-major order. Tiles at the right and bottom edges are clipped if the dimensions aren't exact
+/// multiples of the tile size. The tiles are disjoint, so they may be processed in parallel.
+pub struct TilesMut<'a, T> {
+    view: TooDeeViewMut<'a, T>,
+    cursor: TileCursor,
+}
+
+impl<'a, T> TilesMut<'a, T> {
+    pub(super) fn new(view: TooDeeViewMut<'a, T>, tile_cols: usize, tile_rows: usize) -> TilesMut<'a, T> {
+        let cursor = TileCursor::new(view.num_cols(), view.num_rows(), tile_cols, tile_rows);
+        TilesMut { view, cursor }
+    }
+
+    fn tile(&self, (start, end): (Coordinate, Coordinate)) -> TooDeeViewMut<'a, T> {
+        // Safety: each tile is yielded at most once, the tiles are disjoint, and `self.view` is
+        // only used to create them.
+        unsafe { self.view.sub_view_mut(start, end) }
+    }
+}
+
+impl<'a, T> Iterator for TilesMut<'a, T> {
+
+    type Item = TooDeeViewMut<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next().map(|bounds| self.tile(bounds))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.len(), Some(self.cursor.len()))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.nth(n).map(|bounds| self.tile(bounds))
+    }
+}
+
+impl<T> DoubleEndedIterator for TilesMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cursor.next_back().map(|bounds| self.tile(bounds))
+    }
+}
+
+impl<T> ExactSizeIterator for TilesMut<'_, T> {}
+
+impl<T> Debug for TilesMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TilesMut").field("tile_cols", &self.cursor.tile_cols).field("tile_rows", &self.cursor.tile_rows).field("len", &self.len()).finish()
+    }
+}
+
+/// An iterator over each cell of a `TooDee[View]`, along with the cell's coordinate, in Hilbert curve
+/// order. Consecutive cells are always adjacent, which gives good locality. Areas that aren't square
+/// with a power of two side length are traversed in row-major order instead.
+pub struct HilbertCells<'a, T> {
+    view: TooDeeView<'a, T>,
+    /// Whether the Hilbert curve is used, rather than row-major order.
+    hilbert: bool,
+    /// The index (distance along the curve) of the next cell from the front.
+    front: usize,
+    /// One past the index of the next cell from the back.
+    back: usize,
+}
+
+impl<'a, T> HilbertCells<'a, T> {
+    pub(super) fn new(view: TooDeeView<'a, T>) -> HilbertCells<'a, T> {
+        let (num_cols, num_rows) = view.size();
+        let hilbert = num_cols == num_rows && num_cols.is_power_of_two();
+        HilbertCells { view, hilbert, front: 0, back: num_cols * num_rows }
+    }
+
+    fn coord(&self, index: usize) -> Coordinate {
+        if self.hilbert {
+            hilbert_coord(self.view.num_cols(), index)
+        } else {
+            (index % self.view.num_cols(), index / self.view.num_cols())
+        }
+    }
+}
+
+/// Converts a distance along the Hilbert curve that fills an `n` x `n` square (where `n` is a power
+/// of two) into a `(col, row)` coordinate. Each iteration places the point within the quadrant of the
+/// next largest square, rotating or reflecting it so that the sub-curves join up.
+fn hilbert_coord(n: usize, index: usize) -> Coordinate {
+    let (mut col, mut row) = (0, 0);
+    let mut t = index;
+    let mut s = 1;
+    while s < n {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+        if ry == 0 {
+            if rx == 1 {
+                col = s - 1 - col;
+                row = s - 1 - row;
+            }
+            core::mem::swap(&mut col, &mut row);
+        }
+        col += s * rx;
+        row += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    (col, row)
+}
+
+impl<T> Clone for HilbertCells<'_, T> {
+    fn clone(&self) -> Self {
+        HilbertCells { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for HilbertCells<'a, T> {
+
+    type Item = (Coordinate, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            let coord = self.coord(self.front);
+            self.front += 1;
+            Some((coord, self.view.cell(coord)))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front += n.min(self.back - self.front);
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for HilbertCells<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            let coord = self.coord(self.back);
+            Some((coord, self.view.cell(coord)))
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for HilbertCells<'_, T> {}
+
+impl<T> Debug for HilbertCells<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HilbertCells").field("hilbert", &self.hilbert).field("len", &self.len()).finish()
+    }
+}
+
+/// The `(col, row)` offsets of the 8 neighbours of a cell, in row-major order.
+pub(crate) const NEIGHBOUR_OFFSETS : [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// Offsets `coord` (which must be within `size`), wrapping at the edges of an area of the given `size`.
+/// Each component of `offset` must be no greater in magnitude than the corresponding dimension.
+#[inline]
+pub(crate) fn wrapping_offset(coord: Coordinate, offset: (isize, isize), size: (usize, usize)) -> Coordinate {
+    let wrap = |v: usize, d: isize, len: usize| (v + len).wrapping_add_signed(d) % len;
+    (wrap(coord.0, offset.0, size.0), wrap(coord.1, offset.1, size.1))
+}
+
+/// An iterator over the 8 neighbours of a cell in a `TooDee[View]`, wrapping at the edges.
+/// Each item is the neighbour's coordinate along with the neighbour's value.
+pub struct WrappingNeighbours<'a, T> {
+    rows: Rows<'a, T>,
+    coord: Coordinate,
+    /// The range of remaining offsets in `NEIGHBOUR_OFFSETS`.
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> WrappingNeighbours<'a, T> {
+    pub(super) fn new(rows: Rows<'a, T>, coord: Coordinate) -> WrappingNeighbours<'a, T> {
+        assert!(coord.0 < rows.cols);
+        assert!(coord.1 < rows.rows);
+        WrappingNeighbours { rows, coord, front: 0, back: NEIGHBOUR_OFFSETS.len() }
+    }
+
+    fn neighbour(&self, index: usize) -> (Coordinate, &'a T) {
+        let coord = wrapping_offset(self.coord, NEIGHBOUR_OFFSETS[index], (self.rows.cols, self.rows.rows));
+        // the wrapped coordinate is always within the area
+        let cell = unsafe { &*self.rows.ptr.as_ptr().add(coord.1 * self.rows.stride + coord.0) };
+        (coord, cell)
+    }
+}
+
+impl<T> Clone for WrappingNeighbours<'_, T> {
+    fn clone(&self) -> Self {
+        WrappingNeighbours { rows: self.rows.clone(), ..*self }
+    }
+}
+
+impl<'a, T> Iterator for WrappingNeighbours<'a, T> {
+
+    type Item = (Coordinate, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+            Some(self.neighbour(self.front - 1))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for WrappingNeighbours<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.neighbour(self.back))
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for WrappingNeighbours<'_, T> {}
+
+impl<T> Debug for WrappingNeighbours<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WrappingNeighbours").field("coord", &self.coord).field("len", &self.len()).finish()
+    }
+}
@@ -17,11 +17,18 @@ A lightweight two-dimensional wrapper around a `Vec`.
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod iter;
 mod view;
 mod ops;
 mod toodee;
 mod flattenexact;
+mod error;
+mod builder;
+mod sat;
+mod stack;
 
 #[cfg(feature = "sort")] mod sort;
 #[cfg(feature = "sort")] mod tests_sort;
@@ -37,14 +44,35 @@ mod flattenexact;
 
 #[cfg(feature = "serde")] mod serde;
 #[cfg(feature = "serde")] mod tests_serde;
+#[cfg(feature = "serde")] pub use crate::serde::{serialize_rle, deserialize_rle};
+
+#[cfg(feature = "image")] mod image;
+#[cfg(feature = "image")] mod tests_image;
+#[cfg(feature = "image")] pub use crate::image::*;
+
+#[cfg(feature = "rand")] mod shuffle;
+#[cfg(feature = "rand")] mod tests_shuffle;
+#[cfg(feature = "rand")] pub use crate::shuffle::*;
+
+#[cfg(feature = "crc")] mod crc;
+#[cfg(feature = "crc")] mod tests_crc;
+#[cfg(feature = "crc")] pub use crate::crc::*;
 
 mod tests;
 mod tests_view;
 mod tests_iter;
+mod tests_error;
+mod tests_builder;
+mod tests_sat;
+mod tests_stack;
 
 pub use crate::iter::*;
 pub use crate::view::*;
 pub use crate::ops::*;
 pub use crate::toodee::*;
 pub use crate::flattenexact::*;
+pub use crate::error::*;
+pub use crate::builder::*;
+pub use crate::sat::*;
+pub use crate::stack::*;
 
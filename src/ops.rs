@@ -1,10 +1,19 @@
-use core::ops::{Index, IndexMut};
+use core::iter::StepBy;
+use core::ops::{Index, IndexMut, Add, Mul, Bound, Range, RangeBounds};
 use core::ptr;
 use core::mem;
+use core::cmp::Ordering;
+use core::fmt::{Display, Write};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
 
 use crate::iter::*;
 use crate::view::*;
+use crate::toodee::*;
 use crate::flattenexact::*;
+use crate::error::*;
 
 /// A `(col, row)` coordinate in 2D space.
 pub type Coordinate = (usize, usize);
@@ -23,6 +32,32 @@ pub trait TooDeeOps<T> : Index<usize, Output=[T]> + Index<Coordinate, Output=T>
     /// The number of rows in the area represented by this object.
     fn num_rows(&self) -> usize;
     
+    /// The number of rows, i.e., the length of `rows()`. This is O(1), and is clearer than `rows().len()`.
+    #[inline]
+    fn rows_len(&self) -> usize {
+        self.num_rows()
+    }
+
+    /// The number of columns, i.e., the length of each row. This is O(1).
+    #[inline]
+    fn cols_len(&self) -> usize {
+        self.num_cols()
+    }
+
+    /// Returns `true` if the area is square, and can therefore be transposed in place, e.g., using
+    /// `TooDee::transpose_square()`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// assert!(TooDee::init(3, 3, 0u32).can_transpose_in_place());
+    /// assert!(!TooDee::init(3, 2, 0u32).can_transpose_in_place());
+    /// ```
+    fn can_transpose_in_place(&self) -> bool {
+        self.num_cols() == self.num_rows()
+    }
+
     /// Returns the size/dimensions of the current object.
     fn size(&self) -> (usize, usize) {
         (self.num_cols(), self.num_rows())
@@ -45,6 +80,43 @@ pub trait TooDeeOps<T> : Index<usize, Output=[T]> + Index<Coordinate, Output=T>
     /// assert_eq!(view.num_rows(), 3);
     /// ```
     fn view(&self, start: Coordinate, end: Coordinate) -> TooDeeView<'_, T>;
+
+    /// Returns a view of all columns within the specified range of rows.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the range is out of bounds, or its start is greater than its end.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 5, (0u32..10).collect());
+    /// assert_eq!(toodee.slice_rows(1..3).size(), (2, 2));
+    /// assert_eq!(toodee.slice_rows(3..)[0], [6, 7]);
+    /// assert_eq!(toodee.slice_rows(..=1).num_rows(), 2);
+    /// assert_eq!(toodee.slice_rows(..).num_rows(), 5);
+    /// ```
+    fn slice_rows(&self, range: impl RangeBounds<usize>) -> TooDeeView<'_, T>
+    where Self: Sized {
+        let num_rows = self.num_rows();
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s.checked_add(1).expect("start bound overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => {
+                assert!(e < num_rows, "range end is out of bounds");
+                e + 1
+            },
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => num_rows,
+        };
+        assert!(start <= end, "range start is greater than range end");
+        assert!(end <= num_rows, "range end is out of bounds");
+        self.view((0, start), (self.num_cols(), end))
+    }
     
     /// Returns an iterator of slices, where each slice represents an entire row.
     /// 
@@ -60,6 +132,24 @@ pub trait TooDeeOps<T> : Index<usize, Output=[T]> + Index<Coordinate, Output=T>
     /// assert_eq!(sum, 42*50);
     /// ```
     fn rows(&self) -> Rows<'_, T>;
+
+    /// Returns an iterator over every `step`th row, starting with the first row.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `step` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 5, (0u32..10).collect());
+    /// let firsts : Vec<u32> = toodee.step_rows(2).map(|r| r[0]).collect();
+    /// assert_eq!(firsts, vec![0, 4, 8]);
+    /// ```
+    fn step_rows(&self, step: usize) -> StepBy<Rows<'_, T>> {
+        self.rows().step_by(step)
+    }
     
     /// Returns an iterator over a single column. Note that the `Col` iterator is indexable.
     /// 
@@ -76,6 +166,20 @@ pub trait TooDeeOps<T> : Index<usize, Output=[T]> + Index<Coordinate, Output=T>
     /// ```
     fn col(&self, col: usize) -> Col<'_, T>;
 
+    /// Returns an iterator over each column, where each column is represented as a `Col`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1u32, 2, 3, 4, 5, 6]);
+    /// let sums : Vec<u32> = toodee.cols().map(|c| c.sum()).collect();
+    /// assert_eq!(sums, vec![5, 7, 9]);
+    /// ```
+    fn cols(&self) -> Cols<'_, T> {
+        Cols::new(self.rows())
+    }
+
     /// Returns an iterator that traverses all cells within the area.
     /// 
     /// # Examples
@@ -86,10 +190,787 @@ pub trait TooDeeOps<T> : Index<usize, Output=[T]> + Index<Coordinate, Output=T>
     /// let mut sum = toodee.cells().sum::<u32>();
     /// assert_eq!(sum, 42*50);
     /// ```
-    fn cells(&self) -> Cells<'_, T> {
-        FlattenExact::new(self.rows())
+    fn cells(&self) -> Cells<'_, T> {
+        self.rows().into_cells()
+    }
+
+    /// Returns an iterator that traverses all cells within the area, along with each cell's
+    /// `(col, row)` coordinate. The coordinates are relative to the area, whatever its stride.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+    /// let view = toodee.view((2, 1), (4, 3));
+    /// let cells : Vec<_> = view.cells_with_coords().collect();
+    /// assert_eq!(cells, vec![((0, 0), &6), ((1, 0), &7), ((0, 1), &10), ((1, 1), &11)]);
+    /// ```
+    fn cells_with_coords(&self) -> IndexedCells<'_, T> {
+        IndexedCells::new(self.cells(), (0, 0))
+    }
+
+    /// Returns an iterator that traverses all cells within the area in Hilbert curve order, along
+    /// with each cell's `(col, row)` coordinate. Consecutive cells are always adjacent, which
+    /// preserves locality. The curve is only defined for square areas whose side length is a power
+    /// of two; any other area is traversed in row-major order, as per `cells_with_coords()`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 2, vec!['a', 'b', 'c', 'd']);
+    /// let cells : String = toodee.hilbert_cells().map(|(_, &c)| c).collect();
+    /// assert_eq!(cells, "acdb");
+    /// ```
+    fn hilbert_cells(&self) -> HilbertCells<'_, T> {
+        HilbertCells::new(self.view((0, 0), self.size()))
+    }
+
+    /// Returns the sum of all cells, or `T::default()` if the area is empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1u32, 2, 3, 4, 5, 6]);
+    /// assert_eq!(toodee.sum(), 21);
+    /// ```
+    fn sum(&self) -> T
+    where T: Add<Output = T> + Default + Copy {
+        self.cells().fold(T::default(), |acc, &v| acc + v)
+    }
+
+    /// Returns the product of all cells, or `1` if the area is empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1u32, 2, 3, 4, 5, 6]);
+    /// assert_eq!(toodee.product(), 720);
+    /// ```
+    fn product(&self) -> T
+    where T: Mul<Output = T> + From<u8> + Copy {
+        self.cells().fold(T::from(1), |acc, &v| acc * v)
+    }
+
+    /// Returns the mean of all cells. The result is NaN if the area is empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1u32, 2, 3, 4, 5, 6]);
+    /// assert_eq!(toodee.mean(), 3.5);
+    /// ```
+    fn mean(&self) -> f64
+    where T: Into<f64> + Copy {
+        let total : f64 = self.cells().map(|&v| v.into()).sum();
+        total / (self.num_cols() * self.num_rows()) as f64
+    }
+
+    /// Returns an iterator over the `num_cols + num_rows - 1` anti-diagonals of the area, i.e., the
+    /// cells where `col + row` is constant, in increasing order of `col + row`. Each diagonal
+    /// starts at its top-right cell and moves down and to the left. This is the order needed to
+    /// process "wavefronts", where each cell depends on its neighbours above and to the left.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let diagonals : Vec<Vec<u32>> = toodee.diagonals().map(|d| d.copied().collect()).collect();
+    /// assert_eq!(diagonals, vec![vec![1], vec![2, 4], vec![3, 5], vec![6]]);
+    /// ```
+    fn diagonals(&self) -> Diagonals<'_, T> {
+        Diagonals::new(self.rows())
+    }
+
+    /// Returns an iterator over every (overlapping) `win_cols` x `win_rows` window, in row-major order
+    /// of each window's top-left corner. There are `(num_cols - win_cols + 1) * (num_rows - win_rows + 1)`
+    /// windows, or none if the window is larger than the area.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `win_cols` or `win_rows` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 3, (1u32..=9).collect());
+    /// let sums : Vec<u32> = toodee.windows_2d(2, 2).map(|w| w.cells().sum()).collect();
+    /// assert_eq!(sums, vec![12, 16, 24, 28]);
+    /// ```
+    fn windows_2d(&self, win_cols: usize, win_rows: usize) -> Windows2d<'_, T> {
+        Windows2d::new(self.view((0, 0), self.size()), win_cols, win_rows)
+    }
+
+    /// Returns an iterator over the non-overlapping `tile_cols` x `tile_rows` tiles, in row-major
+    /// order. If the dimensions aren't exact multiples of the tile size, the tiles at the right and
+    /// bottom edges are clipped, i.e., they are smaller views rather than being skipped.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `tile_cols` or `tile_rows` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, (0u32..6).collect());
+    /// let sizes : Vec<_> = toodee.tiles(2, 2).map(|t| t.size()).collect();
+    /// assert_eq!(sizes, vec![(2, 2), (1, 2)]);
+    /// ```
+    fn tiles(&self, tile_cols: usize, tile_rows: usize) -> Tiles<'_, T> {
+        Tiles::new(self.view((0, 0), self.size()), tile_cols, tile_rows)
+    }
+
+    /// Returns an iterator over the main diagonal, i.e., `(0, 0)`, `(1, 1)`, and so on, yielding
+    /// `min(num_cols, num_rows)` cells. Use a view to walk an off-diagonal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 3, (1..=9).collect());
+    /// assert_eq!(toodee.diagonal().copied().collect::<Vec<u32>>(), vec![1, 5, 9]);
+    /// // the diagonal starting at (1, 0)
+    /// let upper = toodee.view((1, 0), (3, 3));
+    /// assert_eq!(upper.diagonal().copied().collect::<Vec<u32>>(), vec![2, 6]);
+    /// ```
+    fn diagonal(&self) -> Diagonal<'_, T> {
+        self.rows().into_diagonal()
+    }
+
+    /// Returns an iterator over the 8 neighbours of the cell at `coord`, treating the area as a torus,
+    /// i.e., wrapping at the edges. Neighbours are yielded in row-major order, starting at the top-left
+    /// neighbour. If the area has fewer than 3 columns or rows, some cells will be yielded more than once.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `coord` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 3, (0u32..9).collect());
+    /// let sum : u32 = toodee.neighbors8_wrapping((0, 0)).map(|(_, v)| v).sum();
+    /// assert_eq!(sum, 8 + 6 + 7 + 2 + 1 + 5 + 3 + 4);
+    /// ```
+    fn neighbors8_wrapping(&self, coord: Coordinate) -> WrappingNeighbours<'_, T> {
+        WrappingNeighbours::new(self.rows(), coord)
+    }
+
+    /// Creates a new `TooDee` by calling `f` with each cell and its 8 neighbours, e.g., to step a
+    /// cellular automaton. Neighbours are provided in row-major order, starting at the top-left
+    /// neighbour. When `wrap` is `true`, the area is treated as a torus and every neighbour is `Some`;
+    /// otherwise, neighbours that lie outside of the area are `None`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 1, vec![1, 2, 3]);
+    /// let sums = toodee.map_neighborhood(false, |_, n| n.iter().flatten().copied().sum());
+    /// assert_eq!(sums.data(), &[2, 4, 2]);
+    /// let sums = toodee.map_neighborhood(true, |_, n| n.iter().flatten().copied().sum());
+    /// assert_eq!(sums.data(), &[17, 16, 15]);
+    /// ```
+    fn map_neighborhood<F>(&self, wrap: bool, mut f: F) -> TooDee<T>
+    where Self: Sized, F: FnMut(&T, &[Option<&T>; 8]) -> T {
+        let size = self.size();
+        let mut v = Vec::with_capacity(size.0 * size.1);
+        for row in 0..size.1 {
+            for col in 0..size.0 {
+                let neighbours = NEIGHBOUR_OFFSETS.map(|offset| {
+                    if wrap {
+                        Some(&self[wrapping_offset((col, row), offset, size)])
+                    } else {
+                        let c = col.checked_add_signed(offset.0).filter(|&c| c < size.0)?;
+                        let r = row.checked_add_signed(offset.1).filter(|&r| r < size.1)?;
+                        Some(&self[(c, r)])
+                    }
+                });
+                v.push(f(&self[(col, row)], &neighbours));
+            }
+        }
+        TooDee::from_vec(size.0, size.1, v)
+    }
+
+    /// Returns an iterator over runs of consecutive equal rows. Each item is a row along with
+    /// the number of consecutive times it appears.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 4, vec![1, 2, 1, 2, 3, 4, 1, 2]);
+    /// let groups : Vec<_> = toodee.group_consecutive_rows().collect();
+    /// assert_eq!(groups, vec![(&[1, 2][..], 2), (&[3, 4][..], 1), (&[1, 2][..], 1)]);
+    /// ```
+    fn group_consecutive_rows(&self) -> RowGroups<'_, T>
+    where T: PartialEq {
+        RowGroups::new(self.rows())
+    }
+
+    /// Returns the index and contents of the first row that satisfies the predicate, or `None`
+    /// if there is no such row.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 3, vec![1, 10, 2, 20, 3, 30]);
+    /// assert_eq!(toodee.find_row(|r| r[0] == 2), Some((1, &[2, 20][..])));
+    /// assert_eq!(toodee.find_row(|r| r[0] == 4), None);
+    /// ```
+    fn find_row<F>(&self, mut pred: F) -> Option<(usize, &[T])>
+    where Self: Sized, F: FnMut(&[T]) -> bool {
+        self.rows().enumerate().find(|(_, r)| pred(r))
+    }
+
+    /// Returns the index of the first column that satisfies the predicate, or `None` if there
+    /// is no such column. The column itself can be retrieved using `col()`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 0, 0, 2, 0, 3]);
+    /// assert_eq!(toodee.find_col(|mut c| c.all(|&v| v == 0)), Some(1));
+    /// ```
+    fn find_col<F>(&self, mut pred: F) -> Option<usize>
+    where Self: Sized, F: FnMut(Col<'_, T>) -> bool {
+        (0..self.num_cols()).find(|&c| pred(self.col(c)))
+    }
+
+    /// Returns the population variance of all cells, computed in a single pass using Welford's
+    /// algorithm. The result is NaN if the area is empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 2, vec![2u32, 4, 4, 4, 5, 5, 7, 9]);
+    /// assert_eq!(toodee.variance(), 4.0);
+    /// ```
+    fn variance(&self) -> f64
+    where T: Into<f64> + Copy {
+        let mut count = 0f64;
+        let mut mean = 0f64;
+        let mut m2 = 0f64;
+        for &v in self.cells() {
+            let x : f64 = v.into();
+            count += 1.0;
+            let delta = x - mean;
+            mean += delta / count;
+            m2 += delta * (x - mean);
+        }
+        m2 / count
+    }
+
+    /// Returns the population standard deviation of all cells. The result is NaN if the area is empty.
+    /// Requires the `num-traits` feature, which provides a `no_std` square root.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 2, vec![2u32, 4, 4, 4, 5, 5, 7, 9]);
+    /// assert_eq!(toodee.std_dev(), 2.0);
+    /// ```
+    #[cfg(feature = "num-traits")]
+    fn std_dev(&self) -> f64
+    where T: Into<f64> + Copy {
+        // `f64::sqrt()` isn't available in `no_std` builds
+        num_traits::Float::sqrt(self.variance())
+    }
+
+    /// Returns the coordinate of the largest cell, or `None` if the area is empty. Ties are resolved
+    /// in favour of the first cell in row-major order. Cells that cannot be compared to themselves (e.g., NaN)
+    /// are skipped.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1.0, 5.0, f64::NAN, 5.0, 2.0, 0.0]);
+    /// assert_eq!(toodee.argmax(), Some((1, 0)));
+    /// ```
+    fn argmax(&self) -> Option<Coordinate>
+    where T: PartialOrd {
+        find_extremum(self.cells_with_coords(), Ordering::Greater)
+    }
+
+    /// Returns the coordinate of the smallest cell, or `None` if the area is empty. Ties are resolved
+    /// in favour of the first cell in row-major order. Cells that cannot be compared to themselves (e.g., NaN)
+    /// are skipped.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1.0, 5.0, f64::NAN, 5.0, 2.0, 0.0]);
+    /// assert_eq!(toodee.argmin(), Some((2, 1)));
+    /// ```
+    fn argmin(&self) -> Option<Coordinate>
+    where T: PartialOrd {
+        find_extremum(self.cells_with_coords(), Ordering::Less)
+    }
+
+    /// Returns the cells as one `Vec` per column, i.e., in column-major order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(toodee.columns_to_vecs(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    /// ```
+    fn columns_to_vecs(&self) -> Vec<Vec<T>>
+    where T: Clone {
+        (0..self.num_cols()).map(|c| self.col(c).cloned().collect()).collect()
+    }
+
+    /// Creates a new `TooDee` whose rows alternate between the rows of `self` and `other`, starting
+    /// with `self`. Once the shorter of the two runs out of rows, the remaining rows of the other are appended.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if both areas are non-empty and have a different number of columns.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let even = TooDee::from_vec(2, 2, vec![0, 0, 2, 2]);
+    /// let odd = TooDee::from_vec(2, 3, vec![1, 1, 3, 3, 5, 5]);
+    /// let toodee = even.interleave_rows(&odd);
+    /// assert_eq!(toodee.size(), (2, 5));
+    /// assert_eq!(toodee.data(), &[0, 0, 1, 1, 2, 2, 3, 3, 5, 5]);
+    /// ```
+    fn interleave_rows(&self, other: &impl TooDeeOps<T>) -> TooDee<T>
+    where Self: Sized, T: Clone {
+        let num_cols = if self.is_empty() { other.num_cols() } else { self.num_cols() };
+        assert!(other.is_empty() || other.num_cols() == num_cols);
+        let num_rows = self.num_rows() + other.num_rows();
+        let mut v = Vec::with_capacity(num_cols * num_rows);
+        let mut a = self.rows();
+        let mut b = other.rows();
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => break,
+                (ra, rb) => {
+                    for r in [ra, rb].into_iter().flatten() {
+                        v.extend_from_slice(r);
+                    }
+                }
+            }
+        }
+        TooDee::from_vec(num_cols, num_rows, v)
+    }
+
+    /// Returns the indices of the rows that differ between `self` and `other`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `self` and `other` have different dimensions.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let a = TooDee::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let b = TooDee::from_vec(2, 3, vec![1, 2, 3, 0, 5, 6]);
+    /// assert_eq!(a.changed_rows(&b), vec![1]);
+    /// ```
+    fn changed_rows(&self, other: &impl TooDeeOps<T>) -> Vec<usize>
+    where Self: Sized, T: PartialEq {
+        assert_same_dims(self, other);
+        self.rows().zip(other.rows()).enumerate()
+            .filter_map(|(i, (a, b))| if a == b { None } else { Some(i) })
+            .collect()
+    }
+
+    /// Copies the area into a new, contiguous `TooDee`, removing any stride. This is shorthand for
+    /// converting a view of the whole area with `TooDee::from()`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+    /// let packed = toodee.view((1, 1), (3, 3)).to_packed();
+    /// assert_eq!(packed.size(), (2, 2));
+    /// assert_eq!(packed.data(), &[5, 6, 9, 10]);
+    /// ```
+    fn to_packed(&self) -> TooDee<T>
+    where T: Copy {
+        TooDee::from(self.view((0, 0), self.size()))
+    }
+
+    /// Copies the transpose of the area into a new `TooDee`. The cells are copied in blocks
+    /// directly into their transposed positions, which avoids the extra pass (and allocation)
+    /// of converting to a `TooDee` first and then transposing it. The source is read row by row,
+    /// so any view can be transposed directly, whatever its stride.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+    /// let transposed = toodee.view((1, 0), (4, 2)).to_owned_transposed();
+    /// assert_eq!(transposed.size(), (2, 3));
+    /// assert_eq!(transposed.data(), &[1, 5, 2, 6, 3, 7]);
+    /// ```
+    fn to_owned_transposed(&self) -> TooDee<T>
+    where T: Copy + Default {
+        const BLOCK : usize = 32;
+        let (num_cols, num_rows) = self.size();
+        let mut dst = TooDee::new(num_rows, num_cols);
+        let data = dst.data_mut();
+        // work in square blocks so that both the reads and the (strided) writes stay in cache
+        for r0 in (0..num_rows).step_by(BLOCK) {
+            let rows = r0..(r0 + BLOCK).min(num_rows);
+            for c0 in (0..num_cols).step_by(BLOCK) {
+                let cols = c0..(c0 + BLOCK).min(num_cols);
+                for r in rows.clone() {
+                    let row = &self[r][cols.clone()];
+                    for (c, v) in cols.clone().zip(row) {
+                        data[c * num_rows + r] = *v;
+                    }
+                }
+            }
+        }
+        dst
+    }
+
+    /// Labels the connected components of the area, returning a `TooDee<u32>` of labels with the same
+    /// dimensions, along with the number of components found.
+    ///
+    /// Cells that compare equal to `background` are labelled `0`. All other cells are foreground, and
+    /// are grouped using 4-connectivity (i.e., horizontal and vertical neighbours). Components are
+    /// labelled `1..=N` in the order that they are first encountered when scanning row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 3, vec![
+    ///     1, 1, 0, 0,
+    ///     0, 1, 0, 1,
+    ///     0, 0, 0, 1,
+    /// ]);
+    /// let (labels, count) = toodee.label_components(&0);
+    /// assert_eq!(count, 2);
+    /// assert_eq!(labels.data(), &[
+    ///     1, 1, 0, 0,
+    ///     0, 1, 0, 2,
+    ///     0, 0, 0, 2,
+    /// ]);
+    /// ```
+    fn label_components(&self, background: &T) -> (TooDee<u32>, u32)
+    where T: PartialEq {
+        let num_cols = self.num_cols();
+        let num_rows = self.num_rows();
+        let mut labels = TooDee::init(num_cols, num_rows, 0u32);
+        let mut count = 0u32;
+        let mut pending = Vec::new();
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                if labels[(col, row)] != 0 || self[(col, row)] == *background {
+                    continue;
+                }
+                count += 1;
+                labels[(col, row)] = count;
+                pending.push((col, row));
+                // flood the component, depth first
+                while let Some((c, r)) = pending.pop() {
+                    // `wrapping_sub` produces an out-of-bounds coordinate at the edges, which is then skipped
+                    let neighbours = [(c.wrapping_sub(1), r), (c + 1, r), (c, r.wrapping_sub(1)), (c, r + 1)];
+                    for n in neighbours {
+                        if n.0 < num_cols && n.1 < num_rows && labels[n] == 0 && self[n] != *background {
+                            labels[n] = count;
+                            pending.push(n);
+                        }
+                    }
+                }
+            }
+        }
+        (labels, count)
+    }
+
+    /// Applies `f` to the `(2 * radius + 1)` square neighbourhood of each cell, returning the results.
+    /// Only cells whose neighbourhood lies entirely within the area are visited, so the result is
+    /// smaller than the area by `radius` on each side (and is empty if no neighbourhood fits).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+    /// let sums = toodee.map_windows(1, |w| w.cells().sum::<u32>());
+    /// assert_eq!(sums.size(), (2, 1));
+    /// assert_eq!(sums.data(), &[45, 54]);
+    /// ```
+    fn map_windows<U, F>(&self, radius: usize, mut f: F) -> TooDee<U>
+    where Self: Sized, F: FnMut(&TooDeeView<'_, T>) -> U {
+        // a window that overflows can't fit either
+        let size = match radius.checked_mul(2).and_then(|d| d.checked_add(1)) {
+            Some(size) if self.num_cols() >= size && self.num_rows() >= size => size,
+            _ => return TooDee::default(),
+        };
+        let num_cols = self.num_cols() - (size - 1);
+        let num_rows = self.num_rows() - (size - 1);
+        let mut v = Vec::with_capacity(num_cols * num_rows);
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                v.push(f(&self.view((col, row), (col + size, row + size))));
+            }
+        }
+        TooDee::from_vec(num_cols, num_rows, v)
+    }
+
+    /// Renders the area as a table, one line per row. Cells are right-aligned to the width of the
+    /// widest cell in their column, and separated by a single space. An empty area results in an
+    /// empty string.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 200, 3, 40, 5, 6]);
+    /// assert_eq!(toodee.to_table_string(), " 1 200 3\n40   5 6");
+    /// ```
+    fn to_table_string(&self) -> String
+    where T: Display {
+        let num_cols = self.num_cols();
+        let cells : Vec<String> = self.cells().map(|c| c.to_string()).collect();
+        let mut widths = alloc::vec![0; num_cols];
+        for (i, c) in cells.iter().enumerate() {
+            let w = &mut widths[i % num_cols];
+            *w = (*w).max(c.chars().count());
+        }
+        let mut table = String::new();
+        for (i, c) in cells.iter().enumerate() {
+            let col = i % num_cols;
+            if col != 0 {
+                table.push(' ');
+            } else if i != 0 {
+                table.push('\n');
+            }
+            // writing to a `String` never fails
+            let _ = write!(table, "{:>1$}", c, widths[col]);
+        }
+        table
+    }
+
+    /// Renders the area as delimiter-separated values, one line per row, with the cells in each row
+    /// separated by `sep`. No quoting or escaping takes place. An empty area results in an empty string.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 200, 3, 40, 5, 6]);
+    /// assert_eq!(toodee.to_csv(','), "1,200,3\n40,5,6");
+    /// ```
+    fn to_csv(&self, sep: char) -> String
+    where T: Display {
+        let mut csv = String::new();
+        for (i, row) in self.rows().enumerate() {
+            if i != 0 {
+                csv.push('\n');
+            }
+            for (col, c) in row.iter().enumerate() {
+                if col != 0 {
+                    csv.push(sep);
+                }
+                // writing to a `String` never fails
+                let _ = write!(csv, "{}", c);
+            }
+        }
+        csv
+    }
+
+    /// Writes the area, rotated 90 degrees clockwise, into `dst`. No memory is allocated.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `dst`'s dimensions aren't the swapped dimensions of this area, i.e., `(num_rows, num_cols)`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let mut rotated = TooDee::init(2, 3, 0);
+    /// toodee.rotate_90_cw_into(&mut rotated);
+    /// assert_eq!(rotated.data(), &[4, 1, 5, 2, 6, 3]);
+    /// ```
+    fn rotate_90_cw_into(&self, dst: &mut impl TooDeeOpsMut<T>)
+    where Self: Sized, T: Copy {
+        assert_eq!(dst.size(), (self.num_rows(), self.num_cols()));
+        let num_rows = self.num_rows();
+        // row `r` becomes column `num_rows - 1 - r`
+        for (r, row) in self.rows().enumerate() {
+            for (d, s) in dst.col_mut(num_rows - 1 - r).zip(row) {
+                *d = *s;
+            }
+        }
+    }
+
+    /// Returns a copy of the area, rotated 90 degrees clockwise. The source cell `(col, row)`
+    /// becomes `(num_rows - 1 - row, col)` in the result, which has the dimensions `(num_rows, num_cols)`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let rotated = toodee.rotate_cw();
+    /// assert_eq!(rotated.size(), (2, 3));
+    /// assert_eq!(rotated.data(), &[4, 1, 5, 2, 6, 3]);
+    /// ```
+    fn rotate_cw(&self) -> TooDee<T>
+    where T: Clone {
+        let rows: Vec<&[T]> = self.rows().collect();
+        let mut v = Vec::with_capacity(self.num_cols() * self.num_rows());
+        // each column, read from bottom to top, becomes a row
+        for col in 0..self.num_cols() {
+            v.extend(rows.iter().rev().map(|r| r[col].clone()));
+        }
+        TooDee::from_vec(self.num_rows(), self.num_cols(), v)
+    }
+
+    /// Returns a copy of the area, rotated 90 degrees counter-clockwise. The source cell `(col, row)`
+    /// becomes `(row, num_cols - 1 - col)` in the result, which has the dimensions `(num_rows, num_cols)`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let rotated = toodee.rotate_ccw();
+    /// assert_eq!(rotated.size(), (2, 3));
+    /// assert_eq!(rotated.data(), &[3, 6, 2, 5, 1, 4]);
+    /// ```
+    fn rotate_ccw(&self) -> TooDee<T>
+    where T: Clone {
+        let rows: Vec<&[T]> = self.rows().collect();
+        let mut v = Vec::with_capacity(self.num_cols() * self.num_rows());
+        // each column, read from top to bottom, becomes a row, starting with the last column
+        for col in (0..self.num_cols()).rev() {
+            v.extend(rows.iter().map(|r| r[col].clone()));
+        }
+        TooDee::from_vec(self.num_rows(), self.num_cols(), v)
+    }
+
+    /// Returns a copy of the area, rotated by 180 degrees. The dimensions are unchanged.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(toodee.rotate_180().data(), &[6, 5, 4, 3, 2, 1]);
+    /// ```
+    fn rotate_180(&self) -> TooDee<T>
+    where T: Clone {
+        let mut v = Vec::with_capacity(self.num_cols() * self.num_rows());
+        for r in self.rows().rev() {
+            v.extend(r.iter().rev().cloned());
+        }
+        TooDee::from_vec(self.num_cols(), self.num_rows(), v)
+    }
+
+    /// Returns a new array that repeats the area `across` times horizontally and `down` times
+    /// vertically, i.e., with the dimensions `(num_cols * across, num_rows * down)`. The result is
+    /// empty if `across` or `down` is zero.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new dimensions overflow.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(2, 1, vec![1, 2]);
+    /// let tiled = toodee.repeat_tiles(2, 2);
+    /// assert_eq!(tiled.size(), (4, 2));
+    /// assert_eq!(tiled.data(), &[1, 2, 1, 2, 1, 2, 1, 2]);
+    /// ```
+    fn repeat_tiles(&self, across: usize, down: usize) -> TooDee<T>
+    where T: Clone {
+        if across == 0 || down == 0 || self.is_empty() {
+            return TooDee::default();
+        }
+        let overflow = || panic!("{}", TooDeeError::DimensionOverflow);
+        let num_cols = self.num_cols().checked_mul(across).unwrap_or_else(overflow);
+        let num_rows = self.num_rows().checked_mul(down).unwrap_or_else(overflow);
+        let len = checked_size(num_cols, num_rows).unwrap_or_else(|e| panic!("{}", e));
+        let mut v = Vec::with_capacity(len);
+        for _ in 0..down {
+            for r in self.rows() {
+                for _ in 0..across {
+                    v.extend_from_slice(r);
+                }
+            }
+        }
+        TooDee::from_vec(num_cols, num_rows, v)
+    }
+
+    /// Writes the transpose of this area into `dst`, which is typically a view into a larger
+    /// array. Cells outside of `dst` are left untouched.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `dst` does not have `num_rows()` columns and `num_cols()` rows.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let mut dst = TooDee::init(3, 3, 0);
+    /// toodee.transpose_into_view(&mut dst.view_mut((1, 0), (3, 3)));
+    /// assert_eq!(dst.data(), &[0, 1, 4, 0, 2, 5, 0, 3, 6]);
+    /// ```
+    fn transpose_into_view(&self, dst: &mut TooDeeViewMut<'_, T>)
+    where T: Copy {
+        assert_eq!(dst.size(), (self.num_rows(), self.num_cols()));
+        // row `r` becomes column `r`
+        for (r, row) in self.rows().enumerate() {
+            for (d, s) in dst.col_mut(r).zip(row) {
+                *d = *s;
+            }
+        }
+    }
+
+    /// Returns a reference to the cell at the given coordinate, or `None` if the coordinate
+    /// is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee : TooDee<u32> = TooDee::from_vec(3, 2, (0..6).collect());
+    /// assert_eq!(toodee.get((2, 1)), Some(&5));
+    /// assert_eq!(toodee.get((3, 1)), None);
+    /// assert_eq!(toodee.get((0, 2)), None);
+    /// ```
+    fn get(&self, coord: Coordinate) -> Option<&T> {
+        if coord.0 < self.num_cols() && coord.1 < self.num_rows() {
+            Some(&self[coord])
+        } else {
+            None
+        }
     }
-    
+
     /// Returns a row without checking that the row is valid. Generally it's best to use indexing instead, e.g., toodee\[row\]
     /// 
     /// # Safety
@@ -124,6 +1005,83 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
     /// assert_eq!(view.num_rows(), 3);
     /// ```
     fn view_mut(&mut self, start: Coordinate, end: Coordinate) -> TooDeeViewMut<'_, T>;
+
+    /// Returns two mutable views of the current area, where each view is specified as a
+    /// `(start, end)` pair of coordinates. Returns `None` if the two areas overlap.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if either area is out of bounds, as per `view_mut()`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// let (mut a, mut b) = toodee.two_views_mut(((0, 0), (4, 2)), ((2, 2), (10, 5))).unwrap();
+    /// a.fill(1);
+    /// b.fill(2);
+    /// assert_eq!(toodee[(3, 1)], 1);
+    /// assert_eq!(toodee[(3, 2)], 2);
+    /// assert!(toodee.two_views_mut(((0, 0), (4, 3)), ((2, 2), (10, 5))).is_none());
+    /// ```
+    fn two_views_mut(&mut self, a: (Coordinate, Coordinate), b: (Coordinate, Coordinate)) -> Option<(TooDeeViewMut<'_, T>, TooDeeViewMut<'_, T>)> {
+        let size = self.size();
+        self.view_mut((0, 0), size).into_two_views(a, b)
+    }
+
+    /// Splits the area into two disjoint mutable views at the specified row. The first view
+    /// contains the rows `[0, row)`, and the second contains the rows `[row, num_rows)`. If either
+    /// view has no rows, it is empty.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `row > num_rows`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// let (mut top, mut bottom) = toodee.split_at_row_mut(2);
+    /// assert_eq!(top.size(), (10, 2));
+    /// assert_eq!(bottom.size(), (10, 3));
+    /// top.fill(1);
+    /// bottom.fill(2);
+    /// assert_eq!(toodee[(9, 1)], 1);
+    /// assert_eq!(toodee[(0, 2)], 2);
+    /// ```
+    fn split_at_row_mut(&mut self, row: usize) -> (TooDeeViewMut<'_, T>, TooDeeViewMut<'_, T>) {
+        let size = self.size();
+        self.view_mut((0, 0), size).into_split_at_row(row)
+    }
+
+    /// Splits the area into two disjoint mutable views at the specified column. The first view
+    /// contains the columns `[0, col)`, and the second contains the columns `[col, num_cols)`. If
+    /// either view has no columns, it is empty.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `col > num_cols`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// let mut view = toodee.view_mut((0, 0), (10, 5));
+    /// let (mut left, mut right) = view.split_at_col_mut(4);
+    /// assert_eq!(left.size(), (4, 5));
+    /// assert_eq!(right.size(), (6, 5));
+    /// left.fill(1);
+    /// right.fill(2);
+    /// assert_eq!(toodee[(3, 4)], 1);
+    /// assert_eq!(toodee[(4, 4)], 2);
+    /// ```
+    fn split_at_col_mut(&mut self, col: usize) -> (TooDeeViewMut<'_, T>, TooDeeViewMut<'_, T>) {
+        let size = self.size();
+        self.view_mut((0, 0), size).into_split_at_col(col)
+    }
     
     /// Returns a mutable iterator of slices, where each slice represents an entire row.
     /// 
@@ -138,6 +1096,24 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
     /// assert_eq!(toodee.cells().sum::<u32>(), 42*50 - 10 - 20 - 30 - 40);
     /// ```
     fn rows_mut(&mut self) -> RowsMut<'_, T>;
+
+    /// Returns a mutable iterator over every `step`th row, starting with the first row.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `step` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::init(2, 5, 0u32);
+    /// toodee.step_rows_mut(2).for_each(|r| r.fill(1));
+    /// assert_eq!(toodee.col(0).copied().collect::<Vec<_>>(), vec![1, 0, 1, 0, 1]);
+    /// ```
+    fn step_rows_mut(&mut self, step: usize) -> StepBy<RowsMut<'_, T>> {
+        self.rows_mut().step_by(step)
+    }
     
     /// Returns a mutable iterator over a single column. Note that the `ColMut` iterator is indexable.
     /// 
@@ -152,6 +1128,60 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
     /// assert_eq!(toodee.cells().sum::<u32>(), 42*45 + 21*5);
     /// ```
     fn col_mut(&mut self, col: usize) -> ColMut<'_, T>;
+
+    /// Returns a mutable iterator over each column, where each column is represented as a `ColMut`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::init(3, 2, 0u32);
+    /// for (i, col) in toodee.cols_mut().enumerate() {
+    ///     col.for_each(|c| *c = i as u32);
+    /// }
+    /// assert_eq!(toodee.data(), &[0, 1, 2, 0, 1, 2]);
+    /// ```
+    fn cols_mut(&mut self) -> ColsMut<'_, T> {
+        ColsMut::new(self.rows_mut())
+    }
+
+    /// Returns a mutable iterator over the main diagonal, i.e., `(0, 0)`, `(1, 1)`, and so on,
+    /// yielding `min(num_cols, num_rows)` cells.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::init(3, 2, 0u32);
+    /// toodee.diagonal_mut().for_each(|v| *v = 1);
+    /// assert_eq!(toodee.data(), &[1, 0, 0, 0, 1, 0]);
+    /// ```
+    fn diagonal_mut(&mut self) -> DiagonalMut<'_, T> {
+        self.rows_mut().into_diagonal()
+    }
+
+    /// Returns a mutable iterator over the non-overlapping `tile_cols` x `tile_rows` tiles, in
+    /// row-major order. Tiles are clipped at the edges, as per `tiles()`. The tiles are disjoint,
+    /// so they can be processed independently (e.g., in parallel).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `tile_cols` or `tile_rows` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::init(3, 2, 0u32);
+    /// for (i, mut tile) in toodee.tiles_mut(2, 2).enumerate() {
+    ///     tile.fill(i as u32);
+    /// }
+    /// assert_eq!(toodee.data(), &[0, 0, 1, 0, 0, 1]);
+    /// ```
+    fn tiles_mut(&mut self, tile_cols: usize, tile_rows: usize) -> TilesMut<'_, T> {
+        let size = self.size();
+        TilesMut::new(self.view_mut((0, 0), size), tile_cols, tile_rows)
+    }
     
     /// Returns an iterator that traverses all cells within the area.
     /// 
@@ -166,7 +1196,24 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
     /// assert_eq!(toodee.cells().sum::<u32>(), 41*50);
     /// ```
     fn cells_mut(&mut self) -> CellsMut<'_, T> {
-        FlattenExact::new(self.rows_mut())
+        self.rows_mut().into_cells()
+    }
+
+    /// Returns a mutable iterator that traverses all cells within the area, along with each
+    /// cell's `(col, row)` coordinate. The coordinates are relative to the area, whatever its stride.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::init(4, 3, 0usize);
+    /// for ((col, row), v) in toodee.view_mut((2, 1), (4, 3)).cells_with_coords_mut() {
+    ///     *v = 10 * row + col + 1;
+    /// }
+    /// assert_eq!(toodee.data(), &[0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 11, 12]);
+    /// ```
+    fn cells_with_coords_mut(&mut self) -> IndexedCellsMut<'_, T> {
+        IndexedCellsMut::new(self.cells_mut())
     }
     
     /// Fills the entire area with the specified value.
@@ -186,6 +1233,163 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
             r.fill(fill.clone());
         }
     }
+
+    /// Fills the entire area with the specified value, as per `fill()`, returning `false` (and
+    /// doing nothing) if the area is empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(3, 2);
+    /// assert!(toodee.fill_checked(7));
+    /// assert!(!toodee.view_mut((1, 1), (1, 2)).fill_checked(0));
+    /// ```
+    fn fill_checked(&mut self, value: T) -> bool
+    where T: Clone {
+        if self.is_empty() {
+            return false;
+        }
+        self.fill(value);
+        true
+    }
+
+    /// Fills the entire area by calling `f` with each cell's `(col, row)` coordinate, in row-major
+    /// order. The coordinates are relative to the area, not to any parent array.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::init(4, 3, 0u32);
+    /// toodee.view_mut((2, 1), (4, 3)).fill_with(|(col, row)| (10 * row + col) as u32 + 1);
+    /// assert_eq!(toodee.data(), &[0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 11, 12]);
+    /// ```
+    fn fill_with<F>(&mut self, mut f: F)
+    where Self: Sized, F: FnMut(Coordinate) -> T {
+        for (row, r) in self.rows_mut().enumerate() {
+            for (col, v) in r.iter_mut().enumerate() {
+                *v = f((col, row));
+            }
+        }
+    }
+
+    /// Fills the specified range of columns within a single row.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `row` or `cols` are out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(5, 3);
+    /// toodee.fill_row_range(1, 1..4, 9);
+    /// assert_eq!(toodee[1], [0, 9, 9, 9, 0]);
+    /// ```
+    fn fill_row_range(&mut self, row: usize, cols: Range<usize>, value: T)
+    where T: Clone {
+        self[row][cols].fill(value);
+    }
+
+    /// Fills the specified range of rows within a single column.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `col` or `rows` are out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(3, 5);
+    /// toodee.fill_col_range(1, 1..4, 9);
+    /// assert_eq!(toodee.col(1).copied().collect::<Vec<_>>(), vec![0, 9, 9, 9, 0]);
+    /// ```
+    fn fill_col_range(&mut self, col: usize, rows: Range<usize>, value: T)
+    where T: Clone {
+        assert!(rows.start <= rows.end);
+        assert!(rows.end <= self.num_rows());
+        for v in self.col_mut(col).skip(rows.start).take(rows.len()) {
+            *v = value.clone();
+        }
+    }
+
+    /// Fills the outer ring of cells (the first and last rows and columns) with the specified
+    /// value, leaving the interior untouched. If the area has only one or two rows or columns,
+    /// every cell is on the border.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(4, 3);
+    /// toodee.fill_border(1);
+    /// assert_eq!(toodee.data(), &[1, 1, 1, 1,
+    ///                             1, 0, 0, 1,
+    ///                             1, 1, 1, 1]);
+    /// ```
+    fn fill_border(&mut self, value: T)
+    where T: Clone {
+        let num_rows = self.num_rows();
+        let num_cols = self.num_cols();
+        if num_rows == 0 || num_cols == 0 {
+            return;
+        }
+        self[0].fill(value.clone());
+        self[num_rows - 1].fill(value.clone());
+        for row in self.rows_mut().skip(1).take(num_rows.saturating_sub(2)) {
+            row[0] = value.clone();
+            row[num_cols - 1] = value.clone();
+        }
+    }
+    
+    /// Fills the area with a linear gradient from `start` to `end`. The gradient runs from the first
+    /// column to the last if `horizontal` is `true`, otherwise it runs from the first row to the last.
+    /// Requires the `num-traits` feature.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::init(5, 2, 0f32);
+    /// toodee.fill_gradient(0.0, 1.0, true);
+    /// assert_eq!(toodee[1], [0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    #[cfg(feature = "num-traits")]
+    fn fill_gradient(&mut self, start: T, end: T, horizontal: bool)
+    where T: num_traits::Float {
+        let len = if horizontal { self.num_cols() } else { self.num_rows() };
+        // avoid dividing by zero for single column/row areas
+        let denom = T::from(len.saturating_sub(1).max(1)).unwrap();
+        let value = |i: usize| start + (end - start) * (T::from(i).unwrap() / denom);
+        for (i, r) in self.rows_mut().enumerate() {
+            if horizontal {
+                r.iter_mut().enumerate().for_each(|(c, v)| *v = value(c));
+            } else {
+                r.fill(value(i));
+            }
+        }
+    }
+    
+    /// Calls `f(row_index, row)` for each row in parallel, using `rayon`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::init(10, 5, 0u32);
+    /// toodee.par_for_each_row(|i, r| r.fill(i as u32));
+    /// assert_eq!(toodee.col(0).copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn par_for_each_row<F>(&mut self, f: F)
+    where Self: Sized, F: Fn(usize, &mut [T]) + Sync + Send, T: Send {
+        use rayon::prelude::*;
+        let rows: Vec<&mut [T]> = self.rows_mut().collect();
+        rows.into_par_iter().enumerate().for_each(|(i, r)| f(i, r));
+    }
     
     /// Swap/exchange the data between two columns.
     /// 
@@ -216,6 +1420,41 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
         }
     }
 
+    /// Rotates the `size` x `size` region whose top-left cell is `top_left` by 90 degrees clockwise,
+    /// in place. Cells outside the region are untouched, and no allocation takes place.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the region doesn't fit within the array.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::from_vec(3, 2, (0u32..6).collect());
+    /// toodee.rotate_square_region_90_cw((1, 0), 2);
+    /// assert_eq!(toodee.data(), &[0, 4, 1, 3, 5, 2]);
+    /// ```
+    fn rotate_square_region_90_cw(&mut self, top_left: Coordinate, size: usize) {
+        let end_col = top_left.0.checked_add(size).filter(|&c| c <= self.num_cols());
+        let end_row = top_left.1.checked_add(size).filter(|&r| r <= self.num_rows());
+        assert!(end_col.is_some() && end_row.is_some(), "region is out of bounds");
+        if size < 2 {
+            return;
+        }
+        let mut region = self.view_mut(top_left, (top_left.0 + size, top_left.1 + size));
+        let last = size - 1;
+        // rotate each ring, from the outside in, as a series of 4-cycles
+        for layer in 0..size / 2 {
+            for i in layer..last - layer {
+                let top = (i, layer);
+                region.swap(top, (last - layer, i));
+                region.swap(top, (last - i, last - layer));
+                region.swap(top, (layer, last - i));
+            }
+        }
+    }
+
     /// Swap/exchange two cells in the array.
     ///
     /// # Panics
@@ -282,6 +1521,59 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
         let tmp = iter.nth(r1).unwrap();
         tmp.swap_with_slice(iter.nth(r2-r1-1).unwrap());
     }
+
+    /// Reorders the rows so that new row `i` comes from old row `perm[i]`, e.g., using a permutation
+    /// returned by `ArgSortOps::argsort_by_col()`. The rows are moved using the minimum number of swaps.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` isn't a permutation of `0..num_rows`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::from_vec(2, 3, vec![0u32, 0, 1, 1, 2, 2]);
+    /// toodee.apply_row_permutation(&[2, 0, 1]);
+    /// assert_eq!(toodee.data(), &[2, 2, 0, 0, 1, 1]);
+    /// ```
+    fn apply_row_permutation(&mut self, perm: &[usize]) {
+        let mut ordering = permutation_to_ordering(perm, self.num_rows());
+
+        let swap_trace = build_swap_trace(&mut ordering);
+
+        for i in swap_trace.iter() {
+            self.swap_rows(i.0, i.1);
+        }
+    }
+
+    /// Reorders the columns so that new column `i` comes from old column `perm[i]`, e.g., using a
+    /// permutation returned by `ArgSortOps::argsort_by_row()`. The columns are moved using the minimum
+    /// number of swaps.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` isn't a permutation of `0..num_cols`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![0u32, 1, 2, 3, 4, 5]);
+    /// toodee.apply_col_permutation(&[2, 0, 1]);
+    /// assert_eq!(toodee.data(), &[2, 0, 1, 5, 3, 4]);
+    /// ```
+    fn apply_col_permutation(&mut self, perm: &[usize]) {
+        let mut ordering = permutation_to_ordering(perm, self.num_cols());
+
+        let swap_trace = build_swap_trace(&mut ordering);
+
+        for r in self.rows_mut() {
+            for i in swap_trace.iter() {
+                r.swap(i.0, i.1);
+            }
+        }
+    }
     
     /// Return the specified rows as mutable slices.
     /// 
@@ -313,7 +1605,50 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
             (iter.nth(r1-r2-1).unwrap(), tmp)
         }
     }
+
+    /// Return the specified rows as mutable slices, or `None` if `r1` and `r2` are equal, or if either
+    /// row index is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::init(10, 5, 42u32);
+    /// assert!(toodee.get_row_pair_mut(0, 4).is_some());
+    /// assert!(toodee.get_row_pair_mut(4, 4).is_none());
+    /// assert!(toodee.get_row_pair_mut(0, 5).is_none());
+    /// ```
+    fn get_row_pair_mut(&mut self, r1: usize, r2: usize) -> Option<(&mut [T], &mut [T])> {
+        let num_rows = self.num_rows();
+        if r1 == r2 || r1 >= num_rows || r2 >= num_rows {
+            None
+        } else {
+            Some(self.row_pair_mut(r1, r2))
+        }
+    }
     
+    /// Returns a mutable reference to the cell at the given coordinate, or `None` if the
+    /// coordinate is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(3, 2);
+    /// if let Some(v) = toodee.get_mut((1, 1)) {
+    ///     *v = 7;
+    /// }
+    /// assert!(toodee.get_mut((1, 2)).is_none());
+    /// assert_eq!(toodee[(1, 1)], 7);
+    /// ```
+    fn get_mut(&mut self, coord: Coordinate) -> Option<&mut T> {
+        if coord.0 < self.num_cols() && coord.1 < self.num_rows() {
+            Some(&mut self[coord])
+        } else {
+            None
+        }
+    }
+
     /// Returns a mutable row without checking that the row is valid. Generally it's best to use indexing instead, e.g., toodee\[row\]
     /// 
     /// # Safety
@@ -332,3 +1667,79 @@ pub trait TooDeeOpsMut<T> : TooDeeOps<T> + IndexMut<usize,Output=[T]>  + IndexMu
 
 }
 
+/// Finds the first cell that compares as `wanted` against all other (comparable) cells.
+fn find_extremum<T: PartialOrd>(cells: IndexedCells<'_, T>, wanted: Ordering) -> Option<Coordinate> {
+    let mut best : Option<(Coordinate, &T)> = None;
+    for (coord, v) in cells {
+        let replace = match best {
+            Some((_, b)) => v.partial_cmp(b) == Some(wanted),
+            // skip values such as NaN
+            None => v.partial_cmp(v).is_some(),
+        };
+        if replace {
+            best = Some((coord, v));
+        }
+    }
+    best.map(|(coord, _)| coord)
+}
+
+/// Panics with a descriptive message if `a` and `b` have different dimensions.
+#[track_caller]
+pub(crate) fn assert_same_dims<T, U, A, B>(a: &A, b: &B)
+where A: TooDeeOps<T> + ?Sized, B: TooDeeOps<U> + ?Sized {
+    if a.size() != b.size() {
+        panic!("dimension mismatch: self is {}x{}, other is {}x{}", a.num_cols(), a.num_rows(), b.num_cols(), b.num_rows());
+    }
+}
+
+/// Common re-indexing logic used internally by the `SortOps` trait and the permutation methods of
+/// `TooDeeOpsMut`. The first element of each pair is the original index of the item that belongs
+/// at that position.
+pub(crate) fn build_swap_trace(ordering : &mut [(usize,usize)]) ->  &mut [(usize,usize)]
+{
+    let len = ordering.len();
+    
+    // Create a reverse lookup
+    for idx in 0..len {
+        unsafe {
+            // We know 0 <= idx < ordering.len(), so we don't need to check the indexing.
+            let v = ordering.get_unchecked(idx).0;
+            // It's less trivial to figure out that 0 <= v <= ordering.len() - the input
+            // array is created by sorted_box_to_ordering() or permutation_to_ordering()
+            ordering.get_unchecked_mut(v).1 = idx;
+        }
+    }
+    
+    let mut swap_count = 0;
+    
+    // Build a swap trace that will shuffle everything into the right position.
+    for i in 0..len {
+        // Used get_unchecked for the same reason as above
+        unsafe {
+            let (other, inv_i) = *ordering.get_unchecked(i);
+            if i != other {
+                // we re-use the ordering slice to store the swap trace
+                *ordering.get_unchecked_mut(swap_count) = (i, other);
+                swap_count += 1;
+                if inv_i > i {
+                    ordering.get_unchecked_mut(inv_i).0 = other;
+                    ordering.get_unchecked_mut(other).1 = inv_i;
+                }
+            }
+        }
+    }
+    
+    // Only return the portion of the slice containing the swap trace.
+    &mut ordering[..swap_count]
+}
+
+/// Converts a permutation into the ordering expected by `build_swap_trace()`, checking that `perm`
+/// really is a permutation of `0..len`. This guards the unchecked indexing in `build_swap_trace()`.
+fn permutation_to_ordering(perm: &[usize], len: usize) -> Vec<(usize, usize)> {
+    assert_eq!(perm.len(), len, "permutation length mismatch");
+    let mut seen = vec![false; len];
+    for &p in perm {
+        assert!(p < len && !mem::replace(&mut seen[p], true), "not a permutation");
+    }
+    perm.iter().map(|&p| (p, 0)).collect()
+}
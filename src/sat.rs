@@ -0,0 +1,96 @@
+use core::ops::{Add, Sub};
+
+use alloc::vec::Vec;
+
+use crate::ops::*;
+use crate::toodee::*;
+
+/// A summed-area table (integral image), where each cell holds the sum of all cells above and to
+/// the left of it (inclusive) in the source array. Once built, the sum of any rectangular region
+/// can be computed in constant time using `region_sum()`.
+///
+/// The table is a distinct type so that `region_sum()` can't be called on an arbitrary array.
+/// Sums are accumulated using `T`'s `Add` implementation, so the usual overflow rules apply.
+///
+/// # Examples
+///
+/// ```
+/// use toodee::{TooDee,TooDeeOps,SatGrid};
+/// let toodee = TooDee::from_vec(3, 2, vec![1u32, 2, 3, 4, 5, 6]);
+/// let sat = SatGrid::new(&toodee);
+/// assert_eq!(sat.table().data(), &[1, 3, 6, 5, 12, 21]);
+/// assert_eq!(sat.region_sum((1, 0), (2, 1)), 16);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SatGrid<T> {
+    table: TooDee<T>,
+}
+
+impl<T> SatGrid<T> {
+
+    /// Builds the summed-area table for `src`. The table has the same dimensions as `src`.
+    pub fn new(src: &impl TooDeeOps<T>) -> SatGrid<T>
+    where T: Copy + Default + Add<Output = T>
+    {
+        let num_cols = src.num_cols();
+        let mut data: Vec<T> = Vec::with_capacity(num_cols * src.num_rows());
+        for (r, row) in src.rows().enumerate() {
+            let mut running = T::default();
+            for (col, &v) in row.iter().enumerate() {
+                running = running + v;
+                let above = if r > 0 { data[(r - 1) * num_cols + col] } else { T::default() };
+                data.push(running + above);
+            }
+        }
+        SatGrid { table: TooDee::from_vec(num_cols, src.num_rows(), data) }
+    }
+
+    /// Returns the underlying table, where each cell holds the sum of the source cells above
+    /// and to the left of it (inclusive).
+    pub fn table(&self) -> &TooDee<T> {
+        &self.table
+    }
+
+    /// Consumes the `SatGrid`, returning the underlying table.
+    pub fn into_inner(self) -> TooDee<T> {
+        self.table
+    }
+
+    /// Returns the sum of the source cells in the region from `top_left` to `bottom_right`,
+    /// inclusive, using the four corner values of the table.
+    ///
+    /// The terms are combined so that no intermediate result is negative (given non-negative source
+    /// values), which allows unsigned types to be used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bottom_right` is out of bounds, or if `top_left` is below or to the right of
+    /// `bottom_right`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,SatGrid};
+    /// let toodee = TooDee::init(10, 10, 1u32);
+    /// let sat = SatGrid::new(&toodee);
+    /// assert_eq!(sat.region_sum((2, 3), (5, 4)), 8);
+    /// assert_eq!(sat.region_sum((0, 0), (9, 9)), 100);
+    /// ```
+    pub fn region_sum(&self, top_left: Coordinate, bottom_right: Coordinate) -> T
+    where T: Copy + Default + Add<Output = T> + Sub<Output = T>
+    {
+        let (left, top) = top_left;
+        let (right, bottom) = bottom_right;
+        assert!(left <= right && top <= bottom && right < self.table.num_cols() && bottom < self.table.num_rows(),
+            "region is out of bounds");
+        let at = |col: Option<usize>, row: Option<usize>| match (col, row) {
+            (Some(col), Some(row)) => self.table[(col, row)],
+            _ => T::default(),
+        };
+        let (before_left, above_top) = (left.checked_sub(1), top.checked_sub(1));
+        // (columns [left, right] of rows [0, bottom]) - (columns [left, right] of rows [0, top))
+        let upto_bottom = at(Some(right), Some(bottom)) - at(before_left, Some(bottom));
+        let above = at(Some(right), above_top) - at(before_left, above_top);
+        upto_bottom - above
+    }
+}
@@ -3,11 +3,11 @@ use serde::{Serializer,Serialize};
 use crate::toodee::TooDee;
 use crate::view::{TooDeeView,TooDeeViewMut};
 use core::fmt;
-extern crate alloc;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 use serde::ser::SerializeStruct;
 use crate::TooDeeOps;
+use crate::error::checked_size;
 
 struct TooDeeVisitor<T> {
     marker: PhantomData<fn() -> TooDee<T>>
@@ -65,6 +65,9 @@ impl<'de, T> Visitor<'de> for TooDeeVisitor<T>
         if overflow {
             return Err(de::Error::invalid_value(Unexpected::Other("product"),&"dimensions too big"))
         }
+        if (num_cols == 0 || num_rows == 0) && num_cols != num_rows {
+            return Err(de::Error::invalid_value(Unexpected::Other("zero dimension"),&"both dimensions to be zero, or neither"))
+        }
         if product != data.len() {
             return Err(de::Error::invalid_length(product, &"dimensions to match array length"))
         }
@@ -84,7 +87,23 @@ impl<'de, T> Deserialize<'de> for TooDee<T>
     }
 }
 
-impl Serialize for TooDeeView<'_, u32>
+impl<T> Serialize for TooDee<T>
+where T: Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        // the data is already compact, so it can be serialized directly
+        let mut storage = serializer.serialize_struct("TooDee", 3)?;
+        storage.serialize_field("num_cols", &self.num_cols())?;
+        storage.serialize_field("num_rows", &self.num_rows())?;
+        storage.serialize_field("data", self.data())?;
+        storage.end()
+    }
+}
+
+impl<T> Serialize for TooDeeView<'_, T>
+where T: Serialize
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: Serializer
@@ -97,7 +116,8 @@ impl Serialize for TooDeeView<'_, u32>
     }
 }
 
-impl Serialize for TooDeeViewMut<'_, u32>
+impl<T> Serialize for TooDeeViewMut<'_, T>
+where T: Serialize
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: Serializer
@@ -108,4 +128,129 @@ impl Serialize for TooDeeViewMut<'_, u32>
         storage.serialize_field("data", &self.cells().collect::<Vec<_>>())?;
         storage.end()
     }
-}
\ No newline at end of file
+}
+
+/// Run-length encodes the cells, returning `(count, value)` pairs where each `count` is non-zero.
+fn rle_encode<'a, T: PartialEq>(cells: impl Iterator<Item = &'a T>) -> Vec<(usize, &'a T)> {
+    let mut runs : Vec<(usize, &T)> = Vec::new();
+    for cell in cells {
+        match runs.last_mut() {
+            Some((count, value)) if *value == cell => *count += 1,
+            _ => runs.push((1, cell)),
+        }
+    }
+    runs
+}
+
+/// Expands `(count, value)` pairs, such as those produced by `rle_encode()`, back into the cells.
+fn rle_decode<T: Clone>(runs: Vec<(usize, T)>, len: usize) -> Vec<T> {
+    let mut data = Vec::with_capacity(len);
+    for (count, value) in runs {
+        data.resize(data.len() + count, value);
+    }
+    data
+}
+
+/// Serializes the array with its cells run-length encoded, i.e., as `num_cols`, `num_rows`, and a
+/// list of `[count, value]` runs in row-major order. This is far more compact than the standard
+/// representation for grids containing large uniform regions. Intended for use with
+/// `#[serde(serialize_with = "toodee::serialize_rle")]`, and can be read back using `deserialize_rle()`.
+///
+/// # Examples
+///
+/// ```
+/// use toodee::{TooDee,TooDeeOps,serialize_rle};
+/// let toodee = TooDee::from_vec(3, 2, vec![0u8, 0, 0, 0, 7, 7]);
+/// let mut json = Vec::new();
+/// serialize_rle(&toodee, &mut serde_json::Serializer::new(&mut json)).unwrap();
+/// assert_eq!(json, br#"{"num_cols":3,"num_rows":2,"runs":[[4,0],[2,7]]}"#);
+/// ```
+pub fn serialize_rle<T, O, S>(toodee: &O, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + PartialEq,
+    O: TooDeeOps<T> + ?Sized,
+    S: Serializer,
+{
+    let mut storage = serializer.serialize_struct("TooDee", 3)?;
+    storage.serialize_field("num_cols", &toodee.num_cols())?;
+    storage.serialize_field("num_rows", &toodee.num_rows())?;
+    storage.serialize_field("runs", &rle_encode(toodee.rows().flatten()))?;
+    storage.end()
+}
+
+struct RleVisitor<T> {
+    marker: PhantomData<fn() -> TooDee<T>>
+}
+
+const RLE_FIELDS: &[&str] = &["num_cols", "num_rows", "runs"];
+
+impl<'de, T> Visitor<'de> for RleVisitor<T>
+    where T: Deserialize<'de> + Clone
+{
+    type Value = TooDee<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a run-length encoded TooDee array (num_cols, num_rows, runs)")
+    }
+
+    fn visit_map<M>(self, mut visitor: M) -> Result<Self::Value, M::Error>
+        where
+            M: MapAccess<'de>,
+    {
+        let mut num_cols = None;
+        let mut num_rows = None;
+        let mut runs = None;
+        while let Some(key) = visitor.next_key::<&str>()? {
+            match key {
+                "num_cols" => {
+                    if num_cols.is_some() {
+                        return Err(de::Error::duplicate_field("num_cols"));
+                    }
+                    num_cols = Some(visitor.next_value::<usize>()?)
+                },
+                "num_rows" => {
+                    if num_rows.is_some() {
+                        return Err(de::Error::duplicate_field("num_rows"));
+                    }
+                    num_rows = Some(visitor.next_value::<usize>()?)
+                },
+                "runs" => {
+                    if runs.is_some() {
+                        return Err(de::Error::duplicate_field("runs"));
+                    }
+                    runs = Some(visitor.next_value::<Vec<(usize, T)>>()?)
+                },
+                &_ => return Err(de::Error::unknown_field(key, RLE_FIELDS)),
+            }
+        }
+        let num_cols = num_cols.ok_or_else(|| de::Error::missing_field("num_cols"))?;
+        let num_rows = num_rows.ok_or_else(|| de::Error::missing_field("num_rows"))?;
+        let runs = runs.ok_or_else(|| de::Error::missing_field("runs"))?;
+        let len = checked_size(num_cols, num_rows).map_err(de::Error::custom)?;
+        // validate the run lengths before expanding anything
+        let total = runs.iter().try_fold(0usize, |total, (count, _)| total.checked_add(*count));
+        if total != Some(len) {
+            return Err(de::Error::invalid_value(Unexpected::Other("run lengths"), &"run lengths to sum to num_cols * num_rows"))
+        }
+        Ok(TooDee::from_vec(num_cols, num_rows, rle_decode(runs, len)))
+    }
+}
+
+/// Deserializes an array that was serialized using `serialize_rle()`. Intended for use with
+/// `#[serde(deserialize_with = "toodee::deserialize_rle")]`.
+///
+/// # Examples
+///
+/// ```
+/// use toodee::{TooDee,TooDeeOps,deserialize_rle};
+/// let json = r#"{"num_cols":3,"num_rows":2,"runs":[[4,0],[2,7]]}"#;
+/// let toodee : TooDee<u8> = deserialize_rle(&mut serde_json::Deserializer::from_str(json)).unwrap();
+/// assert_eq!(toodee.data(), &[0, 0, 0, 0, 7, 7]);
+/// ```
+pub fn deserialize_rle<'de, T, D>(deserializer: D) -> Result<TooDee<T>, D::Error>
+where
+    T: Deserialize<'de> + Clone,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(RleVisitor { marker: PhantomData })
+}
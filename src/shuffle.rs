@@ -0,0 +1,41 @@
+use rand::Rng;
+
+use crate::ops::*;
+
+/// Provides operations that randomly reorder rows and columns.
+pub trait ShuffleOps<T> : TooDeeOpsMut<T> {
+
+    /// Randomly reorders the rows using a Fisher-Yates shuffle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,ShuffleOps};
+    /// let mut toodee = TooDee::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// toodee.shuffle_rows(&mut rand::thread_rng());
+    /// assert!(toodee.rows().all(|r| r[0] % 2 == 1 && r[1] == r[0] + 1));
+    /// ```
+    fn shuffle_rows<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.num_rows()).rev() {
+            self.swap_rows(i, rng.gen_range(0..=i));
+        }
+    }
+
+    /// Randomly reorders the columns using a Fisher-Yates shuffle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,ShuffleOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// toodee.shuffle_cols(&mut rand::thread_rng());
+    /// assert!(toodee[0].iter().zip(&toodee[1]).all(|(a, b)| b - a == 3));
+    /// ```
+    fn shuffle_cols<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.num_cols()).rev() {
+            self.swap_cols(i, rng.gen_range(0..=i));
+        }
+    }
+}
+
+impl<T, O> ShuffleOps<T> for O where O : TooDeeOpsMut<T> {}
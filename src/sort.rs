@@ -1,50 +1,11 @@
-extern crate alloc;
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::slice;
 use core::ptr;
 
 use crate::ops::*;
 
-/// Common re-indexing logic used internally by the `SortOps` trait.
-fn build_swap_trace(ordering : &mut [(usize,usize)]) ->  &mut [(usize,usize)]
-{
-    let len = ordering.len();
-    
-    // Create a reverse lookup
-    for idx in 0..len {
-        unsafe {
-            // We know 0 <= idx < ordering.len(), so we don't need to check the indexing.
-            let v = ordering.get_unchecked(idx).0;
-            // It's less trivial to figure out that 0 <= v <= ordering.len() - the input
-            // array is created by sorted_box_to_ordering()
-            ordering.get_unchecked_mut(v).1 = idx;
-        }
-    }
-    
-    let mut swap_count = 0;
-    
-    // Build a swap trace that will shuffle everything into the right position.
-    for i in 0..len {
-        // Used get_unchecked for the same reason as above
-        unsafe {
-            let (other, inv_i) = *ordering.get_unchecked(i);
-            if i != other {
-                // we re-use the ordering slice to store the swap trace
-                *ordering.get_unchecked_mut(swap_count) = (i, other);
-                swap_count += 1;
-                if inv_i > i {
-                    ordering.get_unchecked_mut(inv_i).0 = other;
-                    ordering.get_unchecked_mut(other).1 = inv_i;
-                }
-            }
-        }
-    }
-    
-    // Only return the portion of the slice containing the swap trace.
-    &mut ordering[..swap_count]
-}
-
 /// Use some unsafeness to coerce a [(usize, &T)] into a [(usize, usize)]. The `Box` is consumed,
 /// meaning that we "unborrow" the &T values.
 fn sorted_box_to_ordering<T>(sorted: Box<[(usize, &T)]>) -> Box<[(usize,usize)]> {
@@ -83,7 +44,7 @@ pub trait SortOps<T> : TooDeeOpsMut<T> {
     {
         assert!(row < self.num_rows());
         
-        let mut sort_data : Box<[(usize, &T)]> = self[row].iter().enumerate().map(|(i, v)| (i, v)).collect();
+        let mut sort_data : Box<[(usize, &T)]> = self[row].iter().enumerate().collect();
         
         sort_data.sort_by(|i, j| compare(i.1, j.1));
         
@@ -116,7 +77,7 @@ pub trait SortOps<T> : TooDeeOpsMut<T> {
     {
         assert!(row < self.num_rows());
 
-        let mut sort_data : Box<[(usize, &T)]> = self[row].iter().enumerate().map(|(i, v)| (i, v)).collect();
+        let mut sort_data : Box<[(usize, &T)]> = self[row].iter().enumerate().collect();
         
         sort_data.sort_unstable_by(|i, j| compare(i.1, j.1));
 
@@ -177,7 +138,7 @@ pub trait SortOps<T> : TooDeeOpsMut<T> {
     {
         assert!(col < self.num_cols());
         
-        let mut sort_data : Box<[(usize, &T)]> = self.col(col).enumerate().map(|(i, v)| (i, v)).collect();
+        let mut sort_data : Box<[(usize, &T)]> = self.col(col).enumerate().collect();
 
         sort_data.sort_by(|i, j| compare(i.1, j.1));
         
@@ -197,7 +158,7 @@ pub trait SortOps<T> : TooDeeOpsMut<T> {
         F: FnMut(&T, &T) -> Ordering, 
     {
         assert!(col < self.num_cols());
-        let mut sort_data : Box<[(usize, &T)]> = self.col(col).enumerate().map(|(i, v)| (i, v)).collect();
+        let mut sort_data : Box<[(usize, &T)]> = self.col(col).enumerate().collect();
 
         sort_data.sort_unstable_by(|i, j| compare(i.1, j.1));
 
@@ -218,7 +179,7 @@ pub trait SortOps<T> : TooDeeOpsMut<T> {
         B: Ord,
         F: FnMut(&T) -> B,
     {
-        self.sort_by_row(col, |a, b| f(a).cmp(&f(b)));
+        self.sort_by_col(col, |a, b| f(a).cmp(&f(b)));
     }
 
     /// Sort the entire two-dimensional array by comparing elements on a specific column using a key
@@ -229,8 +190,128 @@ pub trait SortOps<T> : TooDeeOpsMut<T> {
         B: Ord,
         F: FnMut(&T) -> B,
     {
-        self.sort_unstable_by_row(col, |a, b| f(a).cmp(&f(b)));
+        self.sort_unstable_by_col(col, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Reorder the rows themselves, comparing entire rows lexicographically using the natural ordering.
+    /// This sort is stable.
+    fn sort_rows(&mut self) where T : Ord {
+        self.sort_rows_by(|a, b| a.cmp(b));
+    }
+
+    /// Reorder the rows themselves, comparing entire rows using the provided compare function.
+    /// This sort is stable.
+    fn sort_rows_by<F>(&mut self, mut compare: F)
+        where
+        F: FnMut(&[T], &[T]) -> Ordering,
+    {
+        let mut indices : Box<[usize]> = (0..self.num_rows()).collect();
+
+        indices.sort_by(|&i, &j| compare(&self[i], &self[j]));
+
+        let mut ordering : Box<[(usize, usize)]> = indices.iter().map(|&i| (i, 0)).collect();
+
+        let swap_trace = build_swap_trace(&mut ordering);
+
+        for i in swap_trace.iter() {
+            self.swap_rows(i.0, i.1);
+        }
+    }
+
+    /// Sort the elements within each row independently, using the natural ordering.
+    /// This sort is stable.
+    fn sort_each_row(&mut self) where T : Ord {
+        self.sort_each_row_by(T::cmp);
+    }
+
+    /// Sort the elements within each row independently, using the provided compare function.
+    /// This sort is stable.
+    fn sort_each_row_by<F>(&mut self, mut compare: F)
+        where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for r in self.rows_mut() {
+            r.sort_by(&mut compare);
+        }
+    }
+
+    /// Sort the elements within each column independently, using the natural ordering.
+    /// This sort is stable.
+    fn sort_each_col(&mut self) where T : Ord {
+        self.sort_each_col_by(T::cmp);
+    }
+
+    /// Sort the elements within each column independently, using the provided compare function.
+    /// This sort is stable.
+    fn sort_each_col_by<F>(&mut self, mut compare: F)
+        where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for col in 0..self.num_cols() {
+            let mut sort_data : Box<[(usize, &T)]> = self.col(col).enumerate().collect();
+
+            sort_data.sort_by(|i, j| compare(i.1, j.1));
+
+            let mut ordering = sorted_box_to_ordering(sort_data);
+
+            let swap_trace = build_swap_trace(&mut ordering);
+
+            for i in swap_trace.iter() {
+                self.swap((col, i.0), (col, i.1));
+            }
+        }
     }
 }
 
 impl<T, O> SortOps<T> for O where O : TooDeeOpsMut<T> {}
+
+/// Provides read-only sorting capabilities, which determine a sort order without moving any data.
+/// The resulting permutation can later be applied using `TooDeeOpsMut::apply_row_permutation()` or
+/// `TooDeeOpsMut::apply_col_permutation()`, e.g., to reorder several parallel arrays in the same way.
+pub trait ArgSortOps<T> : TooDeeOps<T> {
+
+    /// Returns the row indices in the order that sorts the rows by comparing elements in a specific column.
+    /// This sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut,ArgSortOps};
+    /// let mut toodee = TooDee::from_vec(2, 3, vec![3, 30, 1, 10, 2, 20]);
+    /// let perm = toodee.argsort_by_col(0, |a, b| a.cmp(b));
+    /// assert_eq!(perm, vec![1, 2, 0]);
+    /// toodee.apply_row_permutation(&perm);
+    /// assert_eq!(toodee.data(), &[1, 10, 2, 20, 3, 30]);
+    /// ```
+    fn argsort_by_col<F>(&self, col: usize, mut compare: F) -> Vec<usize>
+        where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        assert!(col < self.num_cols());
+        let mut indices : Vec<usize> = (0..self.num_rows()).collect();
+        indices.sort_by(|&i, &j| compare(&self[(col, i)], &self[(col, j)]));
+        indices
+    }
+
+    /// Returns the column indices in the order that sorts the columns by comparing elements in a specific row.
+    /// This sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,ArgSortOps};
+    /// let toodee = TooDee::from_vec(3, 1, vec!['c', 'a', 'b']);
+    /// assert_eq!(toodee.argsort_by_row(0, |a, b| a.cmp(b)), vec![1, 2, 0]);
+    /// ```
+    fn argsort_by_row<F>(&self, row: usize, mut compare: F) -> Vec<usize>
+        where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let r = &self[row];
+        let mut indices : Vec<usize> = (0..r.len()).collect();
+        indices.sort_by(|&i, &j| compare(&r[i], &r[j]));
+        indices
+    }
+}
+
+impl<T, O> ArgSortOps<T> for O where O : TooDeeOps<T> {}
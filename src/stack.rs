@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+
+use crate::ops::*;
+use crate::toodee::*;
+use crate::view::*;
+use crate::error::*;
+
+/// A stack of `TooDee` layers that all share the same dimensions, e.g., the channels of an image
+/// or the frames of an animation. Cells are addressed by `(col, row, layer)`.
+///
+/// # Examples
+///
+/// ```
+/// use toodee::{TooDee,TooDeeOps,GridStack};
+/// let mut stack = GridStack::new();
+/// stack.push_layer(TooDee::init(3, 2, 0u8)).unwrap();
+/// stack.push_layer(TooDee::init(3, 2, 1u8)).unwrap();
+/// assert_eq!(stack.num_layers(), 2);
+/// assert_eq!(stack.cell(2, 1, 1), Some(&1));
+/// assert!(stack.push_layer(TooDee::init(2, 3, 2u8)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridStack<T> {
+    layers: Vec<TooDee<T>>,
+}
+
+impl<T> Default for GridStack<T> {
+    fn default() -> Self {
+        GridStack::new()
+    }
+}
+
+impl<T> GridStack<T> {
+
+    /// Creates an empty stack. The first layer that is pushed determines the dimensions.
+    pub fn new() -> GridStack<T> {
+        GridStack { layers: Vec::new() }
+    }
+
+    /// Creates a stack from the provided layers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooDeeError::DimensionMismatch` for the first layer whose dimensions differ from
+    /// those of the first layer.
+    pub fn from_layers(layers: Vec<TooDee<T>>) -> Result<GridStack<T>, TooDeeError> {
+        if let Some(first) = layers.first() {
+            if let Some(layer) = layers.iter().find(|l| l.size() != first.size()) {
+                return Err(TooDeeError::DimensionMismatch { expected: first.size(), actual: layer.size() });
+            }
+        }
+        Ok(GridStack { layers })
+    }
+
+    /// Adds a layer to the top of the stack.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooDeeError::DimensionMismatch`, and leaves the stack unchanged, if the layer's
+    /// dimensions differ from those of the existing layers.
+    pub fn push_layer(&mut self, layer: TooDee<T>) -> Result<(), TooDeeError> {
+        if let Some(first) = self.layers.first() {
+            if layer.size() != first.size() {
+                return Err(TooDeeError::DimensionMismatch { expected: first.size(), actual: layer.size() });
+            }
+        }
+        self.layers.push(layer);
+        Ok(())
+    }
+
+    /// The `(num_cols, num_rows)` dimensions shared by every layer, or `(0, 0)` if there are no layers.
+    pub fn size(&self) -> (usize, usize) {
+        self.layers.first().map_or((0, 0), |l| l.size())
+    }
+
+    /// The number of layers in the stack.
+    pub fn num_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns the layer at index `z`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` is out of bounds.
+    pub fn layer(&self, z: usize) -> &TooDee<T> {
+        &self.layers[z]
+    }
+
+    /// Returns a mutable view of the layer at index `z`. The layer's cells may be modified, but
+    /// the layer itself can't be replaced, so the dimensions stay consistent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` is out of bounds.
+    pub fn layer_mut(&mut self, z: usize) -> TooDeeViewMut<'_, T> {
+        let layer = &mut self.layers[z];
+        let size = layer.size();
+        layer.view_mut((0, 0), size)
+    }
+
+    /// Returns all of the layers, from the bottom of the stack to the top.
+    pub fn layers(&self) -> &[TooDee<T>] {
+        &self.layers
+    }
+
+    /// Consumes the stack, returning its layers.
+    pub fn into_layers(self) -> Vec<TooDee<T>> {
+        self.layers
+    }
+
+    /// Returns the cell at `(col, row)` in layer `z`, or `None` if any of the indices are out of bounds.
+    pub fn cell(&self, col: usize, row: usize, z: usize) -> Option<&T> {
+        self.layers.get(z)?.get((col, row))
+    }
+
+    /// Returns a mutable reference to the cell at `(col, row)` in layer `z`, or `None` if any of the
+    /// indices are out of bounds.
+    pub fn cell_mut(&mut self, col: usize, row: usize, z: usize) -> Option<&mut T> {
+        self.layers.get_mut(z)?.get_mut((col, row))
+    }
+
+    /// Creates a new stack by applying `f` to each layer, from the bottom up.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooDeeError::DimensionMismatch` if `f` returns layers with differing dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,GridStack};
+    /// let stack = GridStack::from_layers(vec![TooDee::init(2, 2, 1u8), TooDee::init(2, 2, 2u8)]).unwrap();
+    /// let doubled = stack.map_layers(|l| l.map(|&v| v as u32 * 2)).unwrap();
+    /// assert_eq!(doubled.cell(1, 1, 1), Some(&4));
+    /// ```
+    pub fn map_layers<U, F>(&self, f: F) -> Result<GridStack<U>, TooDeeError>
+    where F: FnMut(&TooDee<T>) -> TooDee<U> {
+        GridStack::from_layers(self.layers.iter().map(f).collect())
+    }
+}
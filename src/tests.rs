@@ -116,6 +116,57 @@ mod toodee_tests {
         assert_eq!((8, 2), toodee.size());
     }
 
+    #[test]
+    fn try_init() {
+        let toodee = TooDee::try_init(3, 4, 7u32).unwrap();
+        assert_eq!(toodee.size(), (3, 4));
+        assert!(toodee.cells().all(|&v| v == 7));
+        let empty = TooDee::try_init(0, 0, 7u32).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.size(), (0, 0));
+    }
+
+    #[test]
+    fn try_init_errors() {
+        assert_eq!(TooDee::try_init(0, 5, 0u32), Err(TooDeeError::InconsistentZeroDimension));
+        assert_eq!(TooDee::try_init(5, 0, 0u32), Err(TooDeeError::InconsistentZeroDimension));
+        assert_eq!(TooDee::try_init(usize::MAX, 2, 0u32), Err(TooDeeError::DimensionOverflow));
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let toodee = TooDee::try_from_vec(3, 2, (0u32..6).collect()).unwrap();
+        assert_eq!(toodee, TooDee::from_vec(3, 2, (0u32..6).collect()));
+        let empty = TooDee::try_from_vec(0, 0, Vec::<u32>::new()).unwrap();
+        assert_eq!(empty.size(), (0, 0));
+    }
+
+    #[test]
+    fn try_from_vec_errors() {
+        assert_eq!(TooDee::try_from_vec(3, 2, vec![0u32; 5]), Err(TooDeeError::LengthMismatch { expected: 6, actual: 5 }));
+        assert_eq!(TooDee::try_from_vec(0, 2, Vec::<u32>::new()), Err(TooDeeError::InconsistentZeroDimension));
+        assert_eq!(TooDee::try_from_vec(2, 0, Vec::<u32>::new()), Err(TooDeeError::InconsistentZeroDimension));
+        assert_eq!(TooDee::try_from_vec(usize::MAX, 3, Vec::<u32>::new()), Err(TooDeeError::DimensionOverflow));
+    }
+
+    #[test]
+    fn try_from_box() {
+        let v : Vec<u32> = (0..16).collect();
+        let toodee = TooDee::try_from_box(8, 2, Box::from(v)).unwrap();
+        assert_eq!(7, toodee[0][7]);
+        assert_eq!(11, toodee[1][3]);
+        assert_eq!((8, 2), toodee.size());
+    }
+
+    #[test]
+    fn try_from_box_bad_size() {
+        let v : Vec<u32> = (0..16).collect();
+        let b = TooDee::try_from_box(8, 3, Box::from(v.clone())).unwrap_err();
+        assert_eq!(&*b, v.as_slice());
+        let b = TooDee::try_from_box(usize::MAX, 2, b).unwrap_err();
+        assert_eq!(&*b, v.as_slice());
+    }
+
     #[test]
     #[should_panic]
     fn from_vec_bad_size() {
@@ -409,6 +460,29 @@ mod toodee_tests {
         println!("{}", toodee[1][0]);
     }
 
+    #[test]
+    fn insert_col_from_slice_matches_insert_col() {
+        for (num_cols, num_rows) in [(0, 0), (1, 1), (4, 1), (1, 4), (4, 4), (7, 3)] {
+            let toodee = TooDee::from_vec(num_cols, num_rows, (0u32..(num_cols * num_rows) as u32).collect());
+            let new_col : Vec<u32> = (100..100 + num_rows.max(2) as u32).collect();
+            let new_col = if num_rows == 0 { &new_col[..] } else { &new_col[..num_rows] };
+            for index in 0..=num_cols {
+                let mut expected = toodee.clone();
+                expected.insert_col(index, new_col.iter().copied());
+                let mut actual = toodee.clone();
+                actual.insert_col_from_slice(index, new_col);
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left == right` failed")]
+    fn insert_col_from_slice_bad_len() {
+        let mut toodee = TooDee::from_vec(4, 4, (0u32..16).collect());
+        toodee.insert_col_from_slice(1, &[1, 2, 3]);
+    }
+
     #[test]
     fn insert_col_1_0() {
         let mut toodee : TooDee<u32> = TooDee::from_vec(4, 1, (0u32..4).collect());
@@ -662,6 +736,48 @@ mod toodee_tests {
         toodee.pop_row();
         assert_eq!(toodee.size(), (0usize, 0usize));
     }
+
+    #[test]
+    fn pop_col_until_empty() {
+        let mut toodee = TooDee::from_vec(3, 2, (0..6).map(|v| v.to_string()).collect());
+        while let Some(col) = toodee.pop_col() {
+            assert_eq!(col.count(), 2);
+        }
+        assert_eq!(toodee.size(), (0, 0));
+        assert!(toodee.data().is_empty());
+        assert!(toodee.pop_row().is_none());
+        toodee.push_row(["a".to_string(), "b".to_string()]);
+        toodee.push_col(["c".to_string(), "d".to_string()].into_iter().take(1));
+        assert_eq!(toodee.size(), (3, 1));
+        assert_eq!(toodee.data(), &["a", "b", "c"]);
+    }
+
+    #[test]
+    fn pop_row_until_empty() {
+        let mut toodee = TooDee::from_vec(3, 2, (0..6).map(|v| v.to_string()).collect());
+        while let Some(row) = toodee.pop_row() {
+            assert_eq!(row.count(), 3);
+        }
+        assert_eq!(toodee.size(), (0, 0));
+        assert!(toodee.data().is_empty());
+        assert!(toodee.pop_col().is_none());
+        toodee.push_col(["a".to_string(), "b".to_string()]);
+        toodee.push_row(["c".to_string()]);
+        assert_eq!(toodee.size(), (1, 3));
+        assert_eq!(toodee.data(), &["a", "b", "c"]);
+    }
+
+    #[test]
+    fn push_empty_into_empty() {
+        let mut toodee : TooDee<u32> = TooDee::default();
+        toodee.push_row([]);
+        assert_eq!(toodee.size(), (0, 0));
+        toodee.push_col([]);
+        assert_eq!(toodee.size(), (0, 0));
+        assert!(toodee.data().is_empty());
+        toodee.push_row([1, 2]);
+        assert_eq!(toodee.size(), (2, 1));
+    }
     
     #[test]
     fn remove_row() {
@@ -681,24 +797,65 @@ mod toodee_tests {
     }
 
     #[test]
-    #[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+    #[should_panic(expected = "num_cols * num_rows overflows usize")]
     fn toodee_from_vec_overflow() {
         TooDee::from_vec(usize::MAX, usize::MAX,(0u32..1).collect() );
     }
 
 
     #[test]
-    #[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+    #[should_panic(expected = "num_cols * num_rows overflows usize")]
     fn toodee_new_overflow() {
         TooDee::<u32>::new(usize::MAX, usize::MAX);
     }
 
     #[test]
-    #[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+    #[should_panic(expected = "num_cols * num_rows overflows usize")]
     fn toodee_init_overflow() {
         TooDee::<u32>::init(usize::MAX, usize::MAX, 0u32);
     }
 
+    #[test]
+    fn from_fn() {
+        let mut calls = Vec::new();
+        let toodee = TooDee::from_fn(4, 3, |(c, r)| {
+            calls.push((c, r));
+            r * 10 + c
+        });
+        assert_eq!(toodee.size(), (4, 3));
+        assert_eq!(toodee[(3, 2)], 23);
+        assert_eq!(toodee.capacity(), 12);
+        assert_eq!(calls.len(), 12);
+        assert_eq!(calls[..5], [(0, 0), (1, 0), (2, 0), (3, 0), (0, 1)]);
+        let empty = TooDee::from_fn(0, 0, |_| 0u32);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "one dimension is zero but the other is non-zero")]
+    fn from_fn_zero_dimension() {
+        TooDee::from_fn(0, 3, |_| 0u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "num_cols * num_rows overflows usize")]
+    fn from_fn_overflow() {
+        TooDee::from_fn(usize::MAX, usize::MAX, |_| 0u32);
+    }
+
+    #[test]
+    fn map() {
+        struct NoClone(u32);
+        let toodee = TooDee::from_fn(3, 2, |(c, r)| NoClone((r * 3 + c) as u32));
+        let mapped = toodee.map(|v| v.0 as f64 * 0.5);
+        assert_eq!(mapped.size(), (3, 2));
+        assert_eq!(mapped[(2, 1)], 2.5);
+        let empty : TooDee<u8> = TooDee::default();
+        let mapped = empty.map(|&v| v as f32);
+        assert!(mapped.is_empty());
+        assert_eq!(mapped.size(), (0, 0));
+    }
+
     #[test]
     fn get_unchecked() {
         let mut toodee = TooDee::from_vec(10, 10, (0u32..100).collect());
@@ -712,6 +869,24 @@ mod toodee_tests {
         }
     }
 
+    #[test]
+    fn get_checked() {
+        let mut toodee = TooDee::from_vec(10, 10, (0u32..100).collect());
+        assert_eq!(toodee.get((2, 3)), Some(&32));
+        assert_eq!(toodee.get((10, 3)), None);
+        assert_eq!(toodee.get((2, 10)), None);
+        assert_eq!(toodee.get((usize::MAX, usize::MAX)), None);
+        *toodee.get_mut((9, 9)).unwrap() = 0;
+        assert_eq!(toodee[(9, 9)], 0);
+        assert!(toodee.get_mut((9, 10)).is_none());
+        let view = toodee.view((2, 2), (4, 5));
+        assert_eq!(view.get((1, 2)), Some(&43));
+        assert_eq!(view.get((2, 0)), None);
+        assert_eq!(view.get((0, 3)), None);
+        let empty = TooDee::<u32>::default();
+        assert_eq!(empty.get((0, 0)), None);
+    }
+
     #[test]
     fn get_unchecked_row() {
         let toodee = TooDee::from_vec(3, 3, (0u32..9).collect());
@@ -756,4 +931,990 @@ mod toodee_tests {
         let mut toodee: TooDee<u32> = TooDee::init(2, 2, 0);
         toodee.remove_col(0);
     }
+
+    #[test]
+    fn label_components_two_blobs() {
+        let toodee = TooDee::from_vec(5, 4, vec![
+            1, 1, 0, 0, 0,
+            1, 0, 0, 1, 1,
+            0, 0, 0, 1, 0,
+            0, 0, 1, 1, 0,
+        ]);
+        let (labels, count) = toodee.label_components(&0);
+        assert_eq!(count, 2);
+        assert_eq!(labels[(0, 0)], labels[(1, 0)]);
+        assert_eq!(labels[(0, 0)], labels[(0, 1)]);
+        assert_eq!(labels[(3, 1)], labels[(2, 3)]);
+        assert_ne!(labels[(0, 0)], labels[(3, 1)]);
+        assert_eq!(labels[(2, 0)], 0);
+        assert_eq!(labels.num_cols(), 5);
+        assert_eq!(labels.num_rows(), 4);
+    }
+
+    #[test]
+    fn label_components_diagonal_not_connected() {
+        let toodee = TooDee::from_vec(2, 2, vec![true, false, false, true]);
+        let (labels, count) = toodee.label_components(&false);
+        assert_eq!(count, 2);
+        assert_eq!(labels.data(), &[1, 0, 0, 2]);
+    }
+
+    #[test]
+    fn label_components_empty() {
+        let toodee : TooDee<u32> = TooDee::default();
+        let (labels, count) = toodee.label_components(&0);
+        assert_eq!(count, 0);
+        assert!(labels.is_empty());
+    }
+
+    #[test]
+    fn transpose_blocked() {
+        let toodee = TooDee::from_vec(7, 5, (0u32..35).collect());
+        let mut expected = TooDee::init(5, 7, 0u32);
+        for row in 0..5 {
+            for col in 0..7 {
+                expected[(row, col)] = toodee[(col, row)];
+            }
+        }
+        for block in [1, 2, 3, 8] {
+            let mut transposed = toodee.clone();
+            transposed.transpose_blocked(block);
+            assert_eq!(transposed, expected);
+        }
+    }
+
+    #[test]
+    fn transpose_in_place_rect() {
+        for (num_cols, num_rows) in [(1, 1), (1, 9), (9, 1), (7, 5), (40, 3), (17, 64), (100, 33)] {
+            let toodee = TooDee::from_vec(num_cols, num_rows, (0u32..(num_cols * num_rows) as u32).collect());
+            let mut expected = toodee.clone();
+            expected.transpose_blocked(8);
+            let mut transposed = toodee.clone();
+            transposed.transpose_in_place_rect();
+            assert_eq!(transposed, expected);
+        }
+    }
+
+    #[test]
+    fn transpose_in_place_rect_recursive() {
+        // large enough to split along both dimensions, with uneven halves
+        for (num_cols, num_rows) in [(300, 7), (7, 300), (129, 257), (257, 129), (1000, 1)] {
+            let toodee = TooDee::from_vec(num_cols, num_rows, (0u32..(num_cols * num_rows) as u32).collect());
+            let mut transposed = toodee.clone();
+            transposed.transpose_in_place_rect();
+            assert_eq!(transposed.size(), (num_rows, num_cols));
+            for row in 0..num_rows {
+                for col in 0..num_cols {
+                    assert_eq!(transposed[(row, col)], toodee[(col, row)]);
+                }
+            }
+            transposed.transpose_in_place_rect();
+            assert_eq!(transposed, toodee);
+        }
+    }
+
+    #[test]
+    fn transpose_in_place_rect_empty() {
+        let mut toodee : TooDee<u32> = TooDee::default();
+        toodee.transpose_in_place_rect();
+        assert!(toodee.is_empty());
+    }
+
+    #[test]
+    fn transpose_square() {
+        let mut toodee = TooDee::from_vec(4, 4, (0..16).map(|v| v.to_string()).collect());
+        let original = toodee.clone();
+        toodee.transpose_square();
+        for row in 0..4 {
+            for col in 0..4 {
+                assert_eq!(toodee[(col, row)], original[(row, col)]);
+            }
+        }
+        toodee.transpose_square();
+        assert_eq!(toodee, original);
+        let mut empty : TooDee<String> = TooDee::default();
+        empty.transpose_square();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn transpose_square_sizes() {
+        for n in [1usize, 2, 15, 16, 17, 33, 50] {
+            let toodee = TooDee::from_vec(n, n, (0u32..(n * n) as u32).collect());
+            let mut expected = TooDee::init(n, n, 0u32);
+            for row in 0..n {
+                for col in 0..n {
+                    expected[(row, col)] = toodee[(col, row)];
+                }
+            }
+            let mut transposed = toodee.clone();
+            transposed.transpose_square();
+            assert_eq!(transposed, expected);
+            let mut transposed = toodee.clone();
+            transposed.transpose_in_place_rect();
+            assert_eq!(transposed, expected);
+            for block in [1, 3, 16, 64] {
+                let mut transposed = toodee.clone();
+                transposed.transpose_blocked(block);
+                assert_eq!(transposed, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn transpose_square_does_not_reallocate() {
+        let mut toodee = TooDee::from_vec(20, 20, (0u32..400).collect());
+        let ptr = toodee.data().as_ptr();
+        toodee.transpose_blocked(8);
+        toodee.transpose_in_place_rect();
+        toodee.transpose_square();
+        assert_eq!(toodee.data().as_ptr(), ptr);
+        assert_eq!(toodee[(1, 0)], 20);
+    }
+
+    #[test]
+    fn can_transpose_in_place() {
+        assert!(TooDee::init(5, 5, 0u32).can_transpose_in_place());
+        assert!(!TooDee::init(5, 4, 0u32).can_transpose_in_place());
+        assert!(TooDee::<u32>::default().can_transpose_in_place());
+        let toodee = TooDee::init(5, 4, 0u32);
+        assert!(toodee.view((1, 0), (5, 4)).can_transpose_in_place());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot transpose a non-square array in place: it is 3x2")]
+    fn transpose_square_not_square() {
+        let mut toodee = TooDee::init(3, 2, String::new());
+        toodee.transpose_square();
+    }
+
+    #[test]
+    fn transpose_blocked_empty() {
+        let mut toodee : TooDee<u32> = TooDee::default();
+        toodee.transpose_blocked(4);
+        assert!(toodee.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn transpose_blocked_zero_block() {
+        let mut toodee = TooDee::init(2, 2, 0u32);
+        toodee.transpose_blocked(0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_for_each_row() {
+        let mut toodee = TooDee::init(50, 40, 0u32);
+        let mut expected = toodee.clone();
+        for (i, r) in expected.rows_mut().enumerate() {
+            r.iter_mut().enumerate().for_each(|(c, v)| *v = (i * 100 + c) as u32);
+        }
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        pool.install(|| {
+            toodee.par_for_each_row(|i, r| r.iter_mut().enumerate().for_each(|(c, v)| *v = (i * 100 + c) as u32));
+        });
+        assert_eq!(toodee, expected);
+        let mut view = toodee.view_mut((10, 5), (20, 15));
+        view.par_for_each_row(|i, r| r.fill(i as u32));
+        assert_eq!(toodee[(10, 5)], 0);
+        assert_eq!(toodee[(19, 14)], 9);
+        assert_eq!(toodee[(20, 14)], 1420);
+    }
+
+    #[test]
+    fn step_rows() {
+        let toodee = TooDee::from_vec(3, 10, (0u32..30).collect());
+        let rows : Vec<&[u32]> = toodee.step_rows(3).collect();
+        assert_eq!(rows, vec![&[0, 1, 2], &[9, 10, 11], &[18, 19, 20], &[27, 28, 29]]);
+        let view = toodee.view((1, 1), (3, 10));
+        let firsts : Vec<u32> = view.step_rows(4).map(|r| r[0]).collect();
+        assert_eq!(firsts, vec![4, 16, 28]);
+    }
+
+    #[test]
+    fn step_rows_mut() {
+        let mut toodee = TooDee::init(3, 10, 0u32);
+        toodee.step_rows_mut(3).for_each(|r| r.fill(1));
+        assert_eq!(toodee.col(2).copied().collect::<Vec<_>>(), vec![1, 0, 0, 1, 0, 0, 1, 0, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn step_rows_zero() {
+        let toodee = TooDee::init(3, 10, 0u32);
+        let _ = toodee.step_rows(0);
+    }
+
+    #[test]
+    fn sum_product_mean() {
+        let toodee = TooDee::from_vec(4, 3, (1u32..13).collect());
+        assert_eq!(toodee.sum(), 78);
+        assert_eq!(toodee.product(), 479001600);
+        assert_eq!(toodee.mean(), 6.5);
+        let view = toodee.view((1, 1), (3, 3));
+        assert_eq!(view.sum(), 6 + 7 + 10 + 11);
+        assert_eq!(view.product(), 6 * 7 * 10 * 11);
+        assert_eq!(view.mean(), 8.5);
+    }
+
+    #[test]
+    fn sum_product_mean_float() {
+        let toodee = TooDee::from_vec(2, 2, vec![0.5f32, 1.5, 2.0, 4.0]);
+        assert_eq!(toodee.sum(), 8.0);
+        assert_eq!(toodee.product(), 6.0);
+        assert_eq!(toodee.mean(), 2.0);
+    }
+
+    #[test]
+    fn sum_product_mean_empty() {
+        let toodee : TooDee<u32> = TooDee::default();
+        assert_eq!(toodee.sum(), 0);
+        assert_eq!(toodee.product(), 1);
+        assert!(toodee.mean().is_nan());
+    }
+
+    #[test]
+    fn variance_std_dev() {
+        let toodee = TooDee::from_vec(3, 2, vec![1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!((toodee.variance() - 35.0 / 12.0).abs() < 1e-12);
+        #[cfg(feature = "num-traits")]
+        assert!((toodee.std_dev() - (35.0f64 / 12.0).sqrt()).abs() < 1e-12);
+        let view = toodee.view((1, 0), (3, 2));
+        assert_eq!(view.variance(), 2.5);
+    }
+
+    #[test]
+    fn variance_std_dev_single() {
+        let toodee = TooDee::init(1, 1, 42u8);
+        assert_eq!(toodee.variance(), 0.0);
+        #[cfg(feature = "num-traits")]
+        assert_eq!(toodee.std_dev(), 0.0);
+    }
+
+    #[test]
+    fn variance_empty() {
+        let toodee : TooDee<f32> = TooDee::default();
+        assert!(toodee.variance().is_nan());
+        #[cfg(feature = "num-traits")]
+        assert!(toodee.std_dev().is_nan());
+    }
+
+    #[test]
+    fn argmax_argmin_unique() {
+        let toodee = TooDee::from_vec(3, 3, vec![4, 7, 1, 3, 9, 2, 8, 0, 6]);
+        assert_eq!(toodee.argmax(), Some((1, 1)));
+        assert_eq!(toodee.argmin(), Some((1, 2)));
+        let view = toodee.view((0, 0), (3, 1));
+        assert_eq!(view.argmax(), Some((1, 0)));
+        assert_eq!(view.argmin(), Some((2, 0)));
+    }
+
+    #[test]
+    fn argmax_argmin_ties() {
+        let toodee = TooDee::from_vec(3, 3, vec![2, 1, 9, 1, 9, 2, 9, 1, 2]);
+        assert_eq!(toodee.argmax(), Some((2, 0)));
+        assert_eq!(toodee.argmin(), Some((1, 0)));
+    }
+
+    #[test]
+    fn argmax_argmin_nan() {
+        let toodee = TooDee::from_vec(2, 2, vec![f32::NAN, 1.0, f32::NAN, -1.0]);
+        assert_eq!(toodee.argmax(), Some((1, 0)));
+        assert_eq!(toodee.argmin(), Some((1, 1)));
+        let empty : TooDee<f32> = TooDee::default();
+        assert_eq!(empty.argmax(), None);
+        let all_nan = TooDee::init(2, 2, f32::NAN);
+        assert_eq!(all_nan.argmin(), None);
+    }
+
+    #[test]
+    fn to_table_string() {
+        let toodee = TooDee::from_vec(3, 3, vec![1, 22, 333, 4444, 5, -6, 7, 8, 9]);
+        assert_eq!(toodee.to_table_string(), "   1 22 333\n4444  5  -6\n   7  8   9");
+        let view = toodee.view((1, 0), (3, 2));
+        assert_eq!(view.to_table_string(), "22 333\n 5  -6");
+        let strings = TooDee::from_vec(2, 2, vec!["ab", "c", "", "défg"]);
+        assert_eq!(strings.to_table_string(), "ab    c\n   défg");
+    }
+
+    #[test]
+    fn to_table_string_empty() {
+        let toodee : TooDee<u32> = TooDee::default();
+        assert_eq!(toodee.to_table_string(), "");
+    }
+
+    #[test]
+    fn from_char_lines() {
+        let toodee = TooDee::from_char_lines("abc\ndéf\nghi").unwrap();
+        assert_eq!(toodee.size(), (3, 3));
+        assert_eq!(toodee[0], ['a', 'b', 'c']);
+        assert_eq!(toodee[1], ['d', 'é', 'f']);
+        assert_eq!(toodee[(2, 2)], 'i');
+    }
+
+    #[test]
+    fn from_char_lines_ragged() {
+        let result = TooDee::from_char_lines("abc\nde\nfgh\n");
+        assert_eq!(result, Err(TooDeeError::LengthMismatch { expected: 3, actual: 2 }));
+        let result = TooDee::from_char_lines("abc\n\nfgh\n");
+        assert_eq!(result, Err(TooDeeError::LengthMismatch { expected: 3, actual: 0 }));
+    }
+
+    #[test]
+    fn from_char_lines_trailing_newlines() {
+        let expected = TooDee::from_vec(2, 2, vec!['a', 'b', 'c', 'd']);
+        assert_eq!(TooDee::from_char_lines("ab\ncd\n").unwrap(), expected);
+        assert_eq!(TooDee::from_char_lines("ab\r\ncd\r\n\r\n").unwrap(), expected);
+        assert_eq!(TooDee::from_char_lines("ab\ncd\n\n\n").unwrap(), expected);
+        assert!(TooDee::from_char_lines("\n\n").unwrap().is_empty());
+        assert!(TooDee::from_char_lines("").unwrap().is_empty());
+    }
+
+    fn life(cell: &bool, neighbours: &[Option<&bool>; 8]) -> bool {
+        let alive = neighbours.iter().filter(|&&n| n == Some(&true)).count();
+        alive == 3 || (*cell && alive == 2)
+    }
+
+    #[test]
+    fn map_neighborhood_blinker() {
+        let horizontal = TooDee::from_vec(5, 5, [
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 1, 1, 1, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ].iter().map(|&v| v == 1).collect());
+        let vertical = TooDee::from_vec(5, 5, [
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ].iter().map(|&v| v == 1).collect());
+        let next = horizontal.map_neighborhood(false, life);
+        assert_eq!(next, vertical);
+        assert_eq!(next.map_neighborhood(false, life), horizontal);
+        assert_eq!(horizontal.map_neighborhood(true, life), vertical);
+    }
+
+    #[test]
+    fn map_neighborhood_edges() {
+        let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        let bounded = toodee.map_neighborhood(false, |_, n| n.iter().filter(|v| v.is_some()).count() as u32);
+        assert_eq!(bounded.data(), &[3, 5, 3, 3, 5, 3]);
+        let wrapped = toodee.map_neighborhood(true, |_, n| n.iter().flatten().copied().sum());
+        // with 2 rows, the rows above and below are both the other row
+        assert_eq!(wrapped.data(), &[2 * 15 + 6 - 1, 2 * 15 + 6 - 2, 2 * 15 + 6 - 3, 2 * 6 + 15 - 4, 2 * 6 + 15 - 5, 2 * 6 + 15 - 6]);
+    }
+
+    #[test]
+    fn as_row_arrays() {
+        let toodee = TooDee::from_vec(3, 4, (0u32..12).collect());
+        let rows = toodee.as_row_arrays::<3>().unwrap();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0], [0, 1, 2]);
+        assert_eq!(rows[3], [9, 10, 11]);
+        assert!(toodee.as_row_arrays::<4>().is_none());
+        assert!(toodee.as_row_arrays::<0>().is_none());
+    }
+
+    #[test]
+    fn as_row_arrays_empty() {
+        let toodee : TooDee<u32> = TooDee::default();
+        assert_eq!(toodee.as_row_arrays::<0>(), Some(&[][..]));
+        assert!(toodee.as_row_arrays::<3>().is_none());
+    }
+
+    #[test]
+    fn rows_len_cols_len() {
+        let toodee = TooDee::from_vec(3, 4, (0u32..12).collect());
+        assert_eq!(toodee.rows_len(), toodee.num_rows());
+        assert_eq!(toodee.cols_len(), toodee.num_cols());
+        assert_eq!(toodee.rows_len(), toodee.rows().len());
+        let view = toodee.view((1, 1), (3, 2));
+        assert_eq!((view.cols_len(), view.rows_len()), (2, 1));
+    }
+
+    #[test]
+    fn fill_row_range() {
+        let mut toodee = TooDee::init(6, 6, 0u32);
+        toodee.fill_row_range(1, 2..5, 1);
+        for (coord, &v) in toodee.view((0, 0), (6, 6)).indexed_cells_parent() {
+            assert_eq!(v, u32::from(coord.1 == 1 && (2..5).contains(&coord.0)), "{:?}", coord);
+        }
+    }
+
+    #[test]
+    fn fill_col_range() {
+        let mut toodee = TooDee::init(6, 6, 0u32);
+        toodee.fill_col_range(2, 1..4, 1);
+        for (coord, &v) in toodee.view((0, 0), (6, 6)).indexed_cells_parent() {
+            assert_eq!(v, u32::from(coord.0 == 2 && (1..4).contains(&coord.1)), "{:?}", coord);
+        }
+        let mut view = toodee.view_mut((1, 1), (5, 5));
+        view.fill_col_range(3, 0..4, 2);
+        view.fill_col_range(0, 2..2, 3);
+        assert_eq!(toodee.col(4).copied().collect::<Vec<_>>(), vec![0, 2, 2, 2, 2, 0]);
+        assert!(!toodee.cells().any(|&v| v == 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn fill_col_range_out_of_bounds() {
+        let mut toodee = TooDee::init(6, 6, 0u32);
+        toodee.fill_col_range(2, 3..7, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn fill_row_range_out_of_bounds() {
+        let mut toodee = TooDee::init(6, 6, 0u32);
+        toodee.fill_row_range(2, 3..7, 1);
+    }
+
+    #[test]
+    fn fill_checked() {
+        let mut toodee = TooDee::init(4, 3, 0u32);
+        assert!(toodee.fill_checked(5));
+        assert!(toodee.cells().all(|&v| v == 5));
+        assert!(toodee.view_mut((1, 1), (3, 2)).fill_checked(1));
+        assert_eq!(toodee.cells().sum::<u32>(), 5 * 10 + 2);
+        assert!(!toodee.view_mut((2, 1), (2, 3)).fill_checked(9));
+        assert!(!toodee.cells().any(|&v| v == 9));
+        let mut empty = TooDee::<u32>::default();
+        assert!(!empty.fill_checked(1));
+    }
+
+    #[test]
+    fn fill_with() {
+        let mut toodee = TooDee::init(3, 2, 0usize);
+        let mut calls = Vec::new();
+        toodee.fill_with(|coord| {
+            calls.push(coord);
+            coord.0 + coord.1 * 10
+        });
+        assert_eq!(calls, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        assert_eq!(toodee, TooDee::from_fn(3, 2, |(c, r)| c + r * 10));
+    }
+
+    #[test]
+    fn fill_with_view() {
+        let mut toodee = TooDee::init(5, 4, String::new());
+        let mut view = toodee.view_mut((1, 2), (4, 4));
+        view.fill_with(|(col, row)| format!("{col},{row}"));
+        assert_eq!(toodee[(1, 2)], "0,0");
+        assert_eq!(toodee[(3, 3)], "2,1");
+        assert_eq!(toodee[(4, 3)], "");
+        assert_eq!(toodee.cells().filter(|s| !s.is_empty()).count(), 6);
+        let mut empty = TooDee::<u32>::default();
+        empty.fill_with(|_| unreachable!());
+    }
+
+    #[test]
+    fn fill_border() {
+        let mut toodee = TooDee::from_vec(4, 4, (0u32..16).collect());
+        toodee.fill_border(99);
+        assert_eq!(toodee.cells().filter(|&&v| v == 99).count(), 12);
+        assert_eq!(toodee[(1, 1)], 5);
+        assert_eq!(toodee[(2, 1)], 6);
+        assert_eq!(toodee[(1, 2)], 9);
+        assert_eq!(toodee[(2, 2)], 10);
+    }
+
+    #[test]
+    fn fill_border_thin() {
+        let mut toodee = TooDee::init(5, 1, 0u32);
+        toodee.fill_border(1);
+        assert_eq!(toodee.data(), &[1; 5]);
+        let mut toodee = TooDee::init(1, 5, 0u32);
+        toodee.fill_border(1);
+        assert_eq!(toodee.data(), &[1; 5]);
+        let mut toodee = TooDee::init(1, 1, 0u32);
+        toodee.fill_border(1);
+        assert_eq!(toodee.data(), &[1]);
+        let mut toodee = TooDee::<u32>::default();
+        toodee.fill_border(1);
+        assert!(toodee.is_empty());
+    }
+
+    #[test]
+    fn fill_border_view() {
+        let mut toodee = TooDee::init(5, 5, 0u32);
+        toodee.view_mut((1, 1), (4, 4)).fill_border(1);
+        assert_eq!(toodee.cells().sum::<u32>(), 8);
+        assert_eq!(toodee[(2, 2)], 0);
+        assert_eq!(toodee[(0, 0)], 0);
+        assert_eq!(toodee[(3, 3)], 1);
+    }
+
+    #[test]
+    fn columns_to_vecs() {
+        let toodee = TooDee::from_vec(3, 2, vec!["a", "b", "c", "d", "e", "f"]);
+        let cols = toodee.columns_to_vecs();
+        assert_eq!(cols.len(), 3);
+        assert!(cols.iter().all(|c| c.len() == 2));
+        assert_eq!(cols, vec![vec!["a", "d"], vec!["b", "e"], vec!["c", "f"]]);
+        assert_eq!(toodee.view((1, 0), (3, 1)).columns_to_vecs(), vec![vec!["b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn columns_to_vecs_empty() {
+        let toodee : TooDee<u32> = TooDee::default();
+        assert!(toodee.columns_to_vecs().is_empty());
+    }
+
+    #[test]
+    fn interleave_rows() {
+        let a = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        let b = TooDee::from_vec(3, 2, vec![10, 20, 30, 40, 50, 60]);
+        let toodee = a.interleave_rows(&b);
+        assert_eq!(toodee.size(), (3, 4));
+        assert_eq!(toodee[0], [1, 2, 3]);
+        assert_eq!(toodee[1], [10, 20, 30]);
+        assert_eq!(toodee[2], [4, 5, 6]);
+        assert_eq!(toodee[3], [40, 50, 60]);
+        let toodee = b.view((0, 1), (3, 2)).interleave_rows(&a);
+        assert_eq!(toodee.data(), &[40, 50, 60, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn interleave_rows_empty() {
+        let a = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        let empty = TooDee::default();
+        assert_eq!(a.interleave_rows(&empty), a);
+        assert_eq!(empty.interleave_rows(&a), a);
+        assert!(empty.interleave_rows(&empty).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn interleave_rows_bad_cols() {
+        let a = TooDee::init(3, 2, 0u32);
+        let b = TooDee::init(2, 2, 0u32);
+        a.interleave_rows(&b);
+    }
+
+    #[test]
+    fn get_row_pair_mut() {
+        let mut toodee = TooDee::from_vec(3, 4, (0u32..12).collect());
+        let (a, b) = toodee.get_row_pair_mut(3, 1).unwrap();
+        assert_eq!(a, [9, 10, 11]);
+        assert_eq!(b, [3, 4, 5]);
+        a.swap_with_slice(b);
+        assert_eq!(toodee[1], [9, 10, 11]);
+        assert!(toodee.get_row_pair_mut(2, 2).is_none());
+        assert!(toodee.get_row_pair_mut(0, 4).is_none());
+        assert!(toodee.get_row_pair_mut(4, 0).is_none());
+        let mut view = toodee.view_mut((1, 1), (3, 3));
+        assert_eq!(view.get_row_pair_mut(0, 1).map(|(a, b)| (a.to_vec(), b.to_vec())), Some((vec![10, 11], vec![7, 8])));
+        assert!(view.get_row_pair_mut(0, 2).is_none());
+    }
+
+    #[test]
+    fn find_row() {
+        let toodee = TooDee::from_vec(3, 4, vec![
+            1, 2, 3,
+            7, 0, 0,
+            4, 5, 6,
+            7, 8, 9,
+        ]);
+        assert_eq!(toodee.find_row(|r| r[0] == 7), Some((1, &[7, 0, 0][..])));
+        assert_eq!(toodee.find_row(|r| r[0] == 5), None);
+        let view = toodee.view((1, 1), (3, 4));
+        assert_eq!(view.find_row(|r| r[0] == 8), Some((2, &[8, 9][..])));
+        let empty = TooDee::<u32>::default();
+        assert_eq!(empty.find_row(|_| true), None);
+    }
+
+    #[test]
+    fn find_col() {
+        let toodee = TooDee::from_vec(4, 3, vec![
+            1, 0, 0, 0,
+            2, 3, 0, 0,
+            4, 0, 0, 5,
+        ]);
+        assert_eq!(toodee.find_col(|mut c| c.all(|&v| v == 0)), Some(2));
+        assert_eq!(toodee.find_col(|mut c| c.all(|&v| v == 9)), None);
+        let view = toodee.view((3, 0), (4, 3));
+        assert_eq!(view.find_col(|c| c.sum::<u32>() == 5), Some(0));
+        let empty = TooDee::<u32>::default();
+        assert_eq!(empty.find_col(|_| true), None);
+    }
+
+    #[test]
+    fn resize_grow_and_shrink() {
+        let mut toodee = TooDee::from_vec(3, 3, (0u32..9).collect());
+        toodee.resize(4, 4, 99);
+        assert_eq!(toodee.size(), (4, 4));
+        assert_eq!(toodee.data(), &[0, 1, 2, 99, 3, 4, 5, 99, 6, 7, 8, 99, 99, 99, 99, 99]);
+        toodee.resize(2, 2, 99);
+        assert_eq!(toodee.size(), (2, 2));
+        assert_eq!(toodee.data(), &[0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn resize_mixed() {
+        let mut toodee = TooDee::from_vec(3, 2, (0u32..6).collect());
+        toodee.resize(2, 3, 9);
+        assert_eq!(toodee.data(), &[0, 1, 3, 4, 9, 9]);
+        toodee.resize(4, 1, 7);
+        assert_eq!(toodee.size(), (4, 1));
+        assert_eq!(toodee.data(), &[0, 1, 7, 7]);
+    }
+
+    #[test]
+    fn resize_same_cols() {
+        let mut toodee = TooDee::from_vec(2, 3, (0u32..6).collect());
+        toodee.resize(2, 1, 9);
+        assert_eq!(toodee.data(), &[0, 1]);
+        toodee.resize(2, 2, 9);
+        assert_eq!(toodee.data(), &[0, 1, 9, 9]);
+    }
+
+    #[test]
+    fn resize_zero() {
+        let mut toodee = TooDee::from_vec(2, 3, (0u32..6).collect());
+        toodee.resize(0, 0, 9);
+        assert!(toodee.is_empty());
+        assert_eq!(toodee.size(), (0, 0));
+        toodee.resize(3, 2, 9);
+        assert_eq!(toodee.size(), (3, 2));
+        assert_eq!(toodee.data(), &[9; 6]);
+    }
+
+    #[test]
+    fn resize_drops_removed_cells() {
+        let cell = alloc::rc::Rc::new(());
+        let mut toodee = TooDee::init(4, 4, cell.clone());
+        toodee.resize(2, 5, cell.clone());
+        assert_eq!(alloc::rc::Rc::strong_count(&cell), 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "one dimension is zero but the other is non-zero")]
+    fn resize_inconsistent_zero() {
+        let mut toodee = TooDee::from_vec(2, 3, (0u32..6).collect());
+        toodee.resize(0, 3, 9);
+    }
+
+    #[test]
+    fn changed_rows() {
+        let a = TooDee::from_vec(3, 5, (0u32..15).collect());
+        let mut b = a.clone();
+        assert!(a.changed_rows(&b).is_empty());
+        b[(2, 1)] = 99;
+        b[(0, 3)] = 99;
+        assert_eq!(a.changed_rows(&b), vec![1, 3]);
+        let view = b.view((0, 0), (3, 5));
+        assert_eq!(view.changed_rows(&a), vec![1, 3]);
+        let empty = TooDee::<u32>::default();
+        assert!(empty.changed_rows(&TooDee::default()).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch: self is 3x5, other is 5x3")]
+    fn changed_rows_mismatch() {
+        let a = TooDee::from_vec(3, 5, (0u32..15).collect());
+        let b = TooDee::from_vec(5, 3, (0u32..15).collect());
+        a.changed_rows(&b);
+    }
+
+    #[test]
+    fn vconcat_all() {
+        let a = TooDee::from_vec(3, 2, (0u32..6).collect());
+        let b = TooDee::from_vec(3, 2, (6u32..12).collect());
+        let c = TooDee::from_vec(5, 4, (0u32..20).collect());
+        let c_view = c.view((1, 2), (4, 4));
+        let joined = crate::vconcat_all(&[&a, &b, &c_view]).unwrap();
+        assert_eq!(joined.size(), (3, 6));
+        assert_eq!(joined.data(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 11, 12, 13, 16, 17, 18]);
+        assert_eq!(joined.capacity(), 18);
+    }
+
+    #[test]
+    fn vconcat_all_empty() {
+        let joined = crate::vconcat_all::<u32>(&[]).unwrap();
+        assert!(joined.is_empty());
+        let empty = TooDee::<u32>::default();
+        let a = TooDee::from_vec(2, 1, vec![1u32, 2]);
+        let joined = crate::vconcat_all(&[&empty, &a, &empty]).unwrap();
+        assert_eq!(joined, a);
+    }
+
+    #[test]
+    fn vconcat_all_mismatch() {
+        let a = TooDee::from_vec(3, 2, (0u32..6).collect());
+        let b = TooDee::from_vec(2, 3, (0u32..6).collect());
+        assert_eq!(crate::vconcat_all(&[&a, &b]), Err(TooDeeError::LengthMismatch { expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn rotate_square_region_90_cw() {
+        let mut toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        toodee.rotate_square_region_90_cw((1, 1), 3);
+        assert_eq!(toodee.data(), &[
+            0,  1,  2,  3,  4,
+            5, 16, 11,  6,  9,
+           10, 17, 12,  7, 14,
+           15, 18, 13,  8, 19,
+        ]);
+        // four rotations restore the original
+        for _ in 0..3 {
+            toodee.rotate_square_region_90_cw((1, 1), 3);
+        }
+        assert_eq!(toodee.data(), (0u32..20).collect::<Vec<_>>().as_slice());
+    }
+
+    #[test]
+    fn rotate_square_region_90_cw_even() {
+        let mut toodee = TooDee::from_vec(4, 4, (0u32..16).collect());
+        toodee.rotate_square_region_90_cw((0, 0), 4);
+        assert_eq!(toodee.data(), &[12, 8, 4, 0, 13, 9, 5, 1, 14, 10, 6, 2, 15, 11, 7, 3]);
+        let mut view = toodee.view_mut((2, 2), (4, 4));
+        view.rotate_square_region_90_cw((0, 0), 2);
+        assert_eq!(toodee[2], [14, 10, 7, 6]);
+        assert_eq!(toodee[3], [15, 11, 3, 2]);
+        // degenerate regions are no-ops
+        let copy = toodee.clone();
+        toodee.rotate_square_region_90_cw((3, 3), 1);
+        toodee.rotate_square_region_90_cw((4, 4), 0);
+        assert_eq!(toodee, copy);
+    }
+
+    #[test]
+    #[should_panic(expected = "region is out of bounds")]
+    fn rotate_square_region_90_cw_out_of_bounds() {
+        let mut toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        toodee.rotate_square_region_90_cw((2, 0), 4);
+    }
+
+    #[test]
+    fn rotate_cw() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let rotated = toodee.rotate_cw();
+        assert_eq!(rotated.size(), (3, 4));
+        for row in 0..3 {
+            for col in 0..4 {
+                assert_eq!(rotated[(3 - 1 - row, col)], toodee[(col, row)]);
+            }
+        }
+        let mut into = TooDee::init(3, 4, 0u32);
+        toodee.rotate_90_cw_into(&mut into);
+        assert_eq!(rotated, into);
+    }
+
+    #[test]
+    fn rotate_ccw() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let rotated = toodee.rotate_ccw();
+        assert_eq!(rotated.size(), (3, 4));
+        assert_eq!(rotated.data(), &[3, 7, 11, 2, 6, 10, 1, 5, 9, 0, 4, 8]);
+        assert_eq!(rotated.rotate_cw(), toodee);
+        assert_eq!(toodee.rotate_cw().rotate_cw(), toodee.rotate_180());
+    }
+
+    #[test]
+    fn rotate_view() {
+        let toodee = TooDee::from_vec(4, 4, (0u32..16).collect());
+        let view = toodee.view((1, 1), (4, 3));
+        assert_eq!(view.rotate_cw().data(), &[9, 5, 10, 6, 11, 7]);
+        assert_eq!(view.rotate_ccw().data(), &[7, 11, 6, 10, 5, 9]);
+        assert_eq!(view.rotate_180().data(), &[11, 10, 9, 7, 6, 5]);
+    }
+
+    #[test]
+    fn rotate_clone() {
+        let toodee = TooDee::from_vec(2, 1, vec![String::from("a"), String::from("b")]);
+        assert_eq!(toodee.rotate_cw().data(), &["a", "b"]);
+        assert_eq!(toodee.rotate_ccw().data(), &["b", "a"]);
+        assert_eq!(toodee.rotate_180().data(), &["b", "a"]);
+        let empty = TooDee::<String>::default();
+        assert!(empty.rotate_cw().is_empty() && empty.rotate_ccw().is_empty() && empty.rotate_180().is_empty());
+    }
+
+    #[test]
+    fn transpose_naive() {
+        for (num_cols, num_rows) in [(1, 1), (3, 2), (2, 3), (7, 1), (1, 7), (5, 5), (17, 9), (0, 0)] {
+            let original = TooDee::from_fn(num_cols, num_rows, |(c, r)| (c * 100 + r) as u32);
+            let mut naive = original.clone();
+            naive.transpose_naive();
+            let mut blocked = original.clone();
+            blocked.transpose_blocked(4);
+            let mut rect = original.clone();
+            rect.transpose_in_place_rect();
+            assert_eq!(naive.size(), (num_rows, num_cols));
+            assert_eq!(naive, blocked);
+            assert_eq!(naive, rect);
+            naive.transpose_naive();
+            assert_eq!(naive, original);
+        }
+    }
+
+    #[test]
+    fn transpose_naive_clone() {
+        let mut toodee = TooDee::from_fn(3, 2, |(c, r)| vec![c, r]);
+        toodee.transpose_naive();
+        assert_eq!(toodee.size(), (2, 3));
+        assert_eq!(toodee[(1, 2)], vec![2, 1]);
+    }
+
+    #[test]
+    fn repeat_tiles_checkerboard() {
+        let checkerboard = TooDee::from_vec(2, 2, vec![true, false, false, true]);
+        let tiled = checkerboard.repeat_tiles(3, 2);
+        assert_eq!(tiled.size(), (6, 4));
+        for tile in tiled.tiles(2, 2) {
+            assert_eq!(tile, checkerboard.view((0, 0), (2, 2)));
+        }
+        for row in 0..4 {
+            for col in 0..6 {
+                assert_eq!(tiled[(col, row)], (col + row) % 2 == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn repeat_tiles_view() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let tiled = toodee.view((1, 1), (3, 3)).repeat_tiles(2, 1);
+        assert_eq!(tiled.size(), (4, 2));
+        assert_eq!(tiled.data(), &[5, 6, 5, 6, 9, 10, 9, 10]);
+        assert_eq!(toodee.repeat_tiles(1, 1), toodee);
+    }
+
+    #[test]
+    fn repeat_tiles_empty() {
+        let toodee = TooDee::from_vec(2, 2, vec![1u32, 2, 3, 4]);
+        assert_eq!(toodee.repeat_tiles(0, 3).size(), (0, 0));
+        assert_eq!(toodee.repeat_tiles(3, 0).size(), (0, 0));
+        assert!(TooDee::<u32>::default().repeat_tiles(3, 3).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows usize")]
+    fn repeat_tiles_overflow() {
+        let toodee = TooDee::from_vec(2, 2, vec![1u32, 2, 3, 4]);
+        toodee.repeat_tiles(usize::MAX, 1);
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let toodee = TooDee::from_fn(4, 3, |(c, r)| c as i32 * 10 - r as i32 * 7);
+        let csv = toodee.to_csv(',');
+        assert_eq!(csv.lines().next(), Some("0,10,20,30"));
+        assert_eq!(crate::from_csv::<i32>(&csv, ','), Ok(toodee.clone()));
+        assert_eq!(crate::from_csv::<i32>(&toodee.to_csv('\t'), '\t'), Ok(toodee));
+        let floats = TooDee::from_vec(2, 1, vec![0.5f64, -1.25]);
+        assert_eq!(crate::from_csv::<f64>(&floats.to_csv(';'), ';'), Ok(floats));
+    }
+
+    #[test]
+    fn csv_view() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        assert_eq!(toodee.view((1, 1), (3, 3)).to_csv(','), "5,6\n9,10");
+        assert_eq!(TooDee::<u32>::default().to_csv(','), "");
+    }
+
+    #[test]
+    fn from_csv_line_endings() {
+        let expected = TooDee::from_vec(2, 2, vec![1u8, 2, 3, 4]);
+        assert_eq!(crate::from_csv("1,2\r\n3,4\r\n", ','), Ok(expected.clone()));
+        assert_eq!(crate::from_csv("1,2\n3,4\n\n", ','), Ok(expected));
+        assert_eq!(crate::from_csv::<u8>("", ','), Ok(TooDee::default()));
+        let strings : TooDee<String> = crate::from_csv("a b,c\n,d", ',').unwrap();
+        assert_eq!(strings.data(), &["a b", "c", "", "d"]);
+    }
+
+    #[test]
+    fn from_csv_ragged() {
+        assert_eq!(crate::from_csv::<u32>("1,2,3\n4,5\n6,7,8", ','), Err(TooDeeError::LengthMismatch { expected: 3, actual: 2 }));
+        assert_eq!(crate::from_csv::<u32>("1,2\n3,4,5", ','), Err(TooDeeError::LengthMismatch { expected: 2, actual: 3 }));
+    }
+
+    #[test]
+    fn from_csv_unparseable() {
+        assert_eq!(crate::from_csv::<u32>("1,2\n3,four", ','), Err(TooDeeError::ParseError { row: 1, col: 1 }));
+        assert_eq!(crate::from_csv::<u32>("1, 2", ','), Err(TooDeeError::ParseError { row: 0, col: 1 }));
+        assert_eq!(crate::from_csv::<u8>("256", ','), Err(TooDeeError::ParseError { row: 0, col: 0 }));
+        assert_eq!(crate::from_csv::<u32>("1,2\n3,4\n5,6,x", ','), Err(TooDeeError::ParseError { row: 2, col: 2 }));
+    }
+
+    #[test]
+    fn apply_row_permutation() {
+        let mut toodee = TooDee::from_vec(2, 5, (0u32..10).collect());
+        toodee.apply_row_permutation(&[3, 0, 4, 2, 1]);
+        assert_eq!(toodee.data(), &[6, 7, 0, 1, 8, 9, 4, 5, 2, 3]);
+        toodee.apply_row_permutation(&[0, 1, 2, 3, 4]);
+        assert_eq!(toodee.data(), &[6, 7, 0, 1, 8, 9, 4, 5, 2, 3]);
+        // a view of the bottom three rows
+        toodee.view_mut((0, 2), (2, 5)).apply_row_permutation(&[2, 1, 0]);
+        assert_eq!(toodee.data(), &[6, 7, 0, 1, 2, 3, 4, 5, 8, 9]);
+        let mut empty = TooDee::<u32>::default();
+        empty.apply_row_permutation(&[]);
+    }
+
+    #[test]
+    fn apply_col_permutation() {
+        let mut toodee = TooDee::from_vec(4, 2, (0u32..8).collect());
+        toodee.apply_col_permutation(&[1, 3, 0, 2]);
+        assert_eq!(toodee.data(), &[1, 3, 0, 2, 5, 7, 4, 6]);
+        toodee.view_mut((1, 1), (4, 2)).apply_col_permutation(&[2, 0, 1]);
+        assert_eq!(toodee.data(), &[1, 3, 0, 2, 5, 6, 7, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn apply_row_permutation_duplicate() {
+        let mut toodee = TooDee::init(2, 3, 0u32);
+        toodee.apply_row_permutation(&[0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn apply_col_permutation_out_of_range() {
+        let mut toodee = TooDee::init(2, 3, 0u32);
+        toodee.apply_col_permutation(&[0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation length mismatch")]
+    fn apply_row_permutation_length() {
+        let mut toodee = TooDee::init(2, 3, 0u32);
+        toodee.apply_row_permutation(&[0, 1]);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn fill_gradient_horizontal() {
+        let mut toodee = TooDee::init(5, 1, 0f64);
+        toodee.fill_gradient(2.0, 4.0, true);
+        assert_eq!(toodee.data(), &[2.0, 2.5, 3.0, 3.5, 4.0]);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn fill_gradient_vertical() {
+        let mut toodee = TooDee::init(1, 5, 0f32);
+        toodee.fill_gradient(1.0, 0.0, false);
+        assert_eq!(toodee.data(), &[1.0, 0.75, 0.5, 0.25, 0.0]);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn fill_gradient_view() {
+        let mut toodee = TooDee::init(5, 3, -1f32);
+        toodee.view_mut((2, 1), (5, 3)).fill_gradient(0.0, 1.0, true);
+        assert_eq!(toodee[0], [-1.0; 5]);
+        assert_eq!(toodee[1], [-1.0, -1.0, 0.0, 0.5, 1.0]);
+        assert_eq!(toodee[2], [-1.0, -1.0, 0.0, 0.5, 1.0]);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn fill_gradient_single() {
+        let mut toodee = TooDee::init(1, 1, 0f32);
+        toodee.fill_gradient(3.0, 5.0, true);
+        assert_eq!(toodee.data(), &[3.0]);
+    }
 }
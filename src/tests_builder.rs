@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod toodee_tests_builder {
+
+    use crate::*;
+
+    #[test]
+    fn build_3_by_2() {
+        let toodee = TooDeeBuilder::new(3).row([1u32, 2, 3]).row(vec![4, 5, 6]).build().unwrap();
+        assert_eq!(toodee.size(), (3, 2));
+        assert_eq!(toodee.data(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(toodee[(2, 1)], 6);
+    }
+
+    #[test]
+    fn build_ragged() {
+        let result = TooDeeBuilder::new(3).row([1u32, 2, 3]).row([4, 5]).row([6, 7, 8, 9]).build();
+        assert_eq!(result, Err(TooDeeError::LengthMismatch { expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn build_empty() {
+        let toodee = TooDeeBuilder::<u32>::new(0).build().unwrap();
+        assert!(toodee.is_empty());
+        let result = TooDeeBuilder::<u32>::new(3).build();
+        assert_eq!(result, Err(TooDeeError::InconsistentZeroDimension));
+        let result = TooDeeBuilder::<u32>::new(0).row([]).build();
+        assert_eq!(result, Err(TooDeeError::InconsistentZeroDimension));
+    }
+}
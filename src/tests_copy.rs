@@ -67,4 +67,79 @@ mod toodee_tests_copy {
         assert_eq!(dest.data().iter().sum::<u32>(), (100*100 - 100) / 2);
     }
 
+    #[test]
+    #[should_panic(expected = "dimension mismatch: self is 3x4, other is 2x4")]
+    fn copy_from_toodee_dimension_mismatch() {
+        let mut toodee = TooDee::init(3, 4, 0u32);
+        let src = TooDee::init(2, 4, 1u32);
+        toodee.copy_from_toodee(&src);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch: self is 2x2, other is 3x2")]
+    fn clone_from_toodee_view_dimension_mismatch() {
+        let mut toodee = TooDee::init(4, 4, 0u32);
+        let src = TooDee::init(3, 2, 1u32);
+        toodee.view_mut((0, 0), (2, 2)).clone_from_toodee(&src);
+    }
+
+    #[test]
+    fn swap_with() {
+        let mut a = TooDee::from_vec(3, 2, (0u32..6).collect());
+        let mut b = TooDee::from_vec(3, 2, (10u32..16).collect());
+        a.swap_with(&mut b);
+        assert_eq!(a.data(), &[10, 11, 12, 13, 14, 15]);
+        assert_eq!(b.data(), &[0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn swap_with_views() {
+        let mut toodee = TooDee::from_vec(5, 4, (0..20).map(|v| v.to_string()).collect());
+        let mut other = TooDee::init(2, 2, String::from("x"));
+        toodee.view_mut((2, 1), (4, 3)).swap_with(&mut other);
+        assert_eq!(other.data(), &["7", "8", "12", "13"]);
+        assert_eq!(toodee[1], ["5", "6", "x", "x", "9"]);
+        assert_eq!(toodee[2], ["10", "11", "x", "x", "14"]);
+        // two disjoint regions of the same array
+        let (mut left, mut right) = toodee.split_at_col_mut(2);
+        left.view_mut((0, 0), (2, 4)).swap_with(&mut right.view_mut((1, 0), (3, 4)));
+        assert_eq!(toodee[0], ["3", "4", "2", "0", "1"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch: self is 2x2, other is 2x3")]
+    fn swap_with_dimension_mismatch() {
+        let mut a = TooDee::init(2, 2, 0u32);
+        let mut b = TooDee::init(2, 3, 0u32);
+        a.swap_with(&mut b);
+    }
+
+    #[test]
+    fn try_copy_within() {
+        let mut toodee = TooDee::from_vec(10, 10, (0u32..100).collect());
+        assert_eq!(toodee.try_copy_within(((0, 0), (2, 2)), (8, 8)), Ok(()));
+        assert_eq!(toodee[(8, 8)], 0);
+        assert_eq!(toodee[(9, 9)], 11);
+    }
+
+    #[test]
+    fn try_copy_within_bad_src() {
+        let mut toodee = TooDee::from_vec(10, 10, (0u32..100).collect());
+        let expected = toodee.clone();
+        assert_eq!(toodee.try_copy_within(((8, 8), (11, 10)), (0, 0)), Err(TooDeeError::OutOfBounds));
+        assert_eq!(toodee.try_copy_within(((3, 3), (2, 4)), (0, 0)), Err(TooDeeError::OutOfBounds));
+        assert_eq!(toodee, expected);
+    }
+
+    #[test]
+    fn try_copy_within_bad_dest() {
+        let mut toodee = TooDee::from_vec(10, 10, (0u32..100).collect());
+        let expected = toodee.clone();
+        assert_eq!(toodee.try_copy_within(((0, 0), (2, 2)), (9, 0)), Err(TooDeeError::OutOfBounds));
+        assert_eq!(toodee.try_copy_within(((0, 0), (2, 2)), (0, 9)), Err(TooDeeError::OutOfBounds));
+        assert_eq!(toodee.try_copy_within(((0, 0), (2, 2)), (usize::MAX, 0)), Err(TooDeeError::OutOfBounds));
+        let mut view = toodee.view_mut((2, 2), (6, 6));
+        assert_eq!(view.try_copy_within(((0, 0), (2, 2)), (3, 3)), Err(TooDeeError::OutOfBounds));
+        assert_eq!(toodee, expected);
+    }
 }
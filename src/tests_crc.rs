@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod toodee_tests_crc {
+
+    use crate::*;
+
+    #[test]
+    fn crc32_known_value() {
+        let toodee = TooDee::from_vec(9, 1, b"123456789".to_vec());
+        assert_eq!(toodee.crc32(), 0xCBF4_3926);
+        let empty : TooDee<u8> = TooDee::default();
+        assert_eq!(empty.crc32(), 0);
+    }
+
+    #[test]
+    fn crc32_identical_grids() {
+        let a = TooDee::from_vec(16, 8, (0u8..128).collect());
+        let b = TooDee::from_vec(16, 8, (0u8..128).collect());
+        assert_eq!(a.crc32(), b.crc32());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn crc32_serde_round_trip() {
+        let a = TooDee::from_vec(16, 8, (0u8..128).collect());
+        let json = serde_json::to_string(&a).unwrap();
+        let c : TooDee<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(c.crc32(), a.crc32());
+    }
+
+    #[test]
+    fn crc32_single_change() {
+        let a = TooDee::from_vec(16, 8, (0u8..128).collect());
+        let mut b = a.clone();
+        b[(5, 3)] ^= 1;
+        assert_ne!(a.crc32(), b.crc32());
+    }
+
+    #[test]
+    fn crc32_view() {
+        let mut toodee = TooDee::from_vec(16, 8, (0u8..128).collect());
+        let view = toodee.view((2, 1), (10, 7));
+        let packed : TooDee<u8> = view.into();
+        assert_eq!(view.crc32(), packed.crc32());
+        assert_eq!(toodee.view_mut((2, 1), (10, 7)).crc32(), packed.crc32());
+    }
+}
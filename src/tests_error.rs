@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod toodee_tests_error {
+
+    use crate::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(TooDeeError::LengthMismatch { expected: 12, actual: 10 }.to_string(), "length mismatch: expected 12, found 10");
+        assert_eq!(TooDeeError::DimensionOverflow.to_string(), "num_cols * num_rows overflows usize");
+        assert_eq!(TooDeeError::InconsistentZeroDimension.to_string(), "one dimension is zero but the other is non-zero");
+        assert_eq!(TooDeeError::OutOfBounds.to_string(), "coordinate or region is out of bounds");
+        assert_eq!(TooDeeError::InvalidFormat.to_string(), "invalid or unsupported data format");
+        assert_eq!(TooDeeError::DimensionMismatch { expected: (3, 2), actual: (2, 3) }.to_string(), "dimension mismatch: expected 3x2, found 2x3");
+        assert_eq!(TooDeeError::ParseError { row: 4, col: 1 }.to_string(), "parse error at row 4, column 1");
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch: expected 24, found 16")]
+    fn from_vec_length_mismatch() {
+        TooDee::from_vec(8, 3, vec![0u32; 16]);
+    }
+
+    #[test]
+    #[should_panic(expected = "one dimension is zero but the other is non-zero")]
+    fn init_inconsistent_zero_dimension() {
+        TooDee::init(0, 3, 0u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "num_cols * num_rows overflows usize")]
+    fn view_dimension_overflow() {
+        let data = [0u32; 4];
+        TooDeeView::new(usize::MAX, 2, &data);
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch: expected 12, found 4")]
+    fn view_mut_length_mismatch() {
+        let mut data = [0u32; 4];
+        TooDeeViewMut::new(4, 3, &mut data);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_error() {
+        let err: Box<dyn std::error::Error> = Box::new(TooDeeError::DimensionOverflow);
+        assert_eq!(err.to_string(), "num_cols * num_rows overflows usize");
+    }
+}
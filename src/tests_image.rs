@@ -0,0 +1,395 @@
+#[cfg(test)]
+mod toodee_tests_image {
+
+    use crate::*;
+
+    #[test]
+    fn distance_transform_l1_single() {
+        let mut toodee = TooDee::init(7, 5, false);
+        toodee[(2, 3)] = true;
+        let distances = toodee.distance_transform_l1();
+        for row in 0..5usize {
+            for col in 0..7usize {
+                let expected = col.abs_diff(2) + row.abs_diff(3);
+                assert_eq!(distances[(col, row)], expected as u32);
+            }
+        }
+    }
+
+    #[test]
+    fn distance_transform_l1_nearest() {
+        let toodee = TooDee::from_vec(5, 1, vec![true, false, false, false, true]);
+        let distances = toodee.distance_transform_l1();
+        assert_eq!(distances.data(), &[0, 1, 2, 1, 0]);
+    }
+
+    #[test]
+    fn distance_transform_l1_no_true_cells() {
+        let toodee = TooDee::init(4, 3, false);
+        let distances = toodee.distance_transform_l1();
+        assert!(distances.cells().all(|&d| d == u32::MAX));
+    }
+
+    #[test]
+    fn dilate_single_cell() {
+        let mut toodee = TooDee::init(7, 7, false);
+        toodee[(3, 3)] = true;
+        let dilated = toodee.dilate(2);
+        for row in 0..7usize {
+            for col in 0..7usize {
+                let inside = (1..=5).contains(&col) && (1..=5).contains(&row);
+                assert_eq!(dilated[(col, row)], inside);
+            }
+        }
+    }
+
+    #[test]
+    fn dilate_at_edge() {
+        let mut toodee = TooDee::init(4, 3, false);
+        toodee[(0, 0)] = true;
+        let dilated = toodee.dilate(1);
+        assert_eq!(dilated.data(), &[
+            true, true, false, false,
+            true, true, false, false,
+            false, false, false, false,
+        ]);
+    }
+
+    #[test]
+    fn erode_block() {
+        let mut toodee = TooDee::init(8, 8, false);
+        for row in 1..7 {
+            for col in 1..7 {
+                toodee[(col, row)] = true;
+            }
+        }
+        let eroded = toodee.erode(1);
+        assert!(eroded[(2, 2)]);
+        assert!(eroded[(5, 5)]);
+        assert!(!eroded[(1, 1)]);
+        assert!(!eroded[(6, 3)]);
+        assert_eq!(eroded.cells().filter(|&&b| b).count(), 16);
+    }
+
+    #[test]
+    fn erode_treats_outside_as_false() {
+        let toodee = TooDee::init(3, 3, true);
+        let eroded = toodee.erode(1);
+        assert_eq!(eroded.cells().filter(|&&b| b).count(), 1);
+        assert!(eroded[(1, 1)]);
+    }
+
+    #[test]
+    fn morphology_radius_zero() {
+        let toodee = TooDee::from_vec(2, 2, vec![true, false, false, true]);
+        assert_eq!(toodee.dilate(0), toodee);
+        assert_eq!(toodee.erode(0), toodee);
+    }
+
+    #[test]
+    fn sample_bilinear_exact() {
+        let toodee = TooDee::from_vec(3, 2, vec![1.0f64, 2.0, 4.0, 8.0, 16.0, 32.0]);
+        for row in 0..2usize {
+            for col in 0..3usize {
+                assert_eq!(toodee.sample_bilinear(col as f32, row as f32), toodee[(col, row)]);
+            }
+        }
+    }
+
+    #[test]
+    fn sample_bilinear_midpoint() {
+        let toodee = TooDee::from_vec(3, 2, vec![1.0f64, 2.0, 4.0, 8.0, 16.0, 32.0]);
+        assert_eq!(toodee.sample_bilinear(0.5, 0.5), (1.0 + 2.0 + 8.0 + 16.0) / 4.0);
+        assert_eq!(toodee.sample_bilinear(1.5, 0.5), (2.0 + 4.0 + 16.0 + 32.0) / 4.0);
+    }
+
+    #[test]
+    fn sample_bilinear_clamped() {
+        let toodee = TooDee::from_vec(2, 2, vec![1.0f32, 2.0, 3.0, 4.0]);
+        assert_eq!(toodee.sample_bilinear(-3.0, -1.0), 1.0);
+        assert_eq!(toodee.sample_bilinear(5.0, 0.0), 2.0);
+        assert_eq!(toodee.sample_bilinear(10.0, 10.0), 4.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn sample_bilinear_empty() {
+        let toodee : TooDee<f32> = TooDee::default();
+        toodee.sample_bilinear(0.0, 0.0);
+    }
+
+    #[test]
+    fn warp_affine_identity() {
+        let toodee = TooDee::from_vec(4, 3, (0..12).map(|v| v as f32).collect());
+        let warped = toodee.warp_affine([1.0, 0.0, 0.0, 0.0, 1.0, 0.0], 4, 3, -1.0);
+        assert_eq!(warped, toodee);
+    }
+
+    #[test]
+    fn warp_affine_rotate_90_cw() {
+        let toodee = TooDee::from_vec(4, 3, (0..12).map(|v| v as f32).collect());
+        // destination (x, y) samples source (y, num_rows - 1 - x)
+        let warped = toodee.warp_affine([0.0, 1.0, 0.0, -1.0, 0.0, 2.0], 3, 4, -1.0);
+        let mut expected = TooDee::init(3, 4, 0.0f32);
+        for row in 0..3usize {
+            for col in 0..4usize {
+                expected[(2 - row, col)] = toodee[(col, row)];
+            }
+        }
+        assert_eq!(warped, expected);
+    }
+
+    #[test]
+    fn warp_affine_fill() {
+        let toodee = TooDee::init(2, 2, 1.0f64);
+        let warped = toodee.warp_affine([1.0, 0.0, 0.0, 0.0, 1.0, 0.0], 3, 3, 0.0);
+        assert_eq!(warped.data(), &[1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn apply_lut_invert() {
+        let mut toodee = TooDee::from_fn(16, 16, |(c, r)| (r * 16 + c) as u8);
+        let invert : [u8; 256] = core::array::from_fn(|v| 255 - v as u8);
+        toodee.apply_lut(&invert);
+        assert_eq!(toodee[(0, 0)], 255);
+        assert_eq!(toodee[(15, 15)], 0);
+        assert!(toodee.cells().enumerate().all(|(i, &v)| v == 255 - i as u8));
+    }
+
+    #[test]
+    fn apply_lut_threshold_view() {
+        let mut toodee = TooDee::from_vec(4, 2, vec![
+            10u8, 200, 127, 128,
+            255, 0, 90, 160,
+        ]);
+        let threshold : [u8; 256] = core::array::from_fn(|v| if v < 128 { 0 } else { 255 });
+        toodee.view_mut((1, 0), (4, 2)).apply_lut(&threshold);
+        assert_eq!(toodee.data(), &[
+            10, 255, 0, 255,
+            255, 0, 0, 255,
+        ]);
+    }
+
+    #[test]
+    fn to_ppm() {
+        let toodee = TooDee::from_vec(2, 2, vec![
+            [255u8, 0, 0], [0, 255, 0],
+            [0, 0, 255], [1, 2, 3],
+        ]);
+        let ppm = toodee.to_ppm();
+        let header = b"P6\n2 2\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(&ppm[header.len()..], &[255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 2, 3]);
+    }
+
+    #[test]
+    fn to_ppm_empty() {
+        let toodee : TooDee<[u8; 3]> = TooDee::default();
+        assert_eq!(toodee.to_ppm(), b"P6\n0 0\n255\n");
+    }
+
+    #[test]
+    fn from_ppm_round_trip() {
+        let toodee = TooDee::from_fn(5, 3, |(c, r)| [c as u8, r as u8, (c * r) as u8]);
+        assert_eq!(from_ppm(&toodee.to_ppm()), Ok(toodee));
+        let empty : TooDee<[u8; 3]> = TooDee::default();
+        assert_eq!(from_ppm(&empty.to_ppm()), Ok(empty));
+    }
+
+    #[test]
+    fn from_ppm_comments_and_whitespace() {
+        let toodee = from_ppm(b"P6 # a comment\n  1\t1 # another\n255\r\x01\x02\x03").unwrap();
+        assert_eq!(toodee.data(), &[[1, 2, 3]]);
+        // the single whitespace after the max value is followed directly by pixel data
+        let toodee = from_ppm(b"P6\n1 1\n255\n\n\n\n").unwrap();
+        assert_eq!(toodee.data(), &[[10, 10, 10]]);
+    }
+
+    #[test]
+    fn from_ppm_truncated() {
+        let ppm = TooDee::init(2, 2, [1u8, 2, 3]).to_ppm();
+        assert_eq!(from_ppm(&ppm[..ppm.len() - 1]), Err(TooDeeError::LengthMismatch { expected: 12, actual: 11 }));
+        assert_eq!(from_ppm(&ppm[..5]), Err(TooDeeError::InvalidFormat));
+        assert_eq!(from_ppm(b""), Err(TooDeeError::InvalidFormat));
+    }
+
+    #[test]
+    fn from_ppm_malformed_header() {
+        assert_eq!(from_ppm(b"P5\n1 1\n255\n\0"), Err(TooDeeError::InvalidFormat));
+        assert_eq!(from_ppm(b"P6\n1 x\n255\n\0\0\0"), Err(TooDeeError::InvalidFormat));
+        assert_eq!(from_ppm(b"P6\n1 -1\n255\n\0\0\0"), Err(TooDeeError::InvalidFormat));
+        assert_eq!(from_ppm(b"P6\n1 1\n65535\n\0\0\0\0\0\0"), Err(TooDeeError::InvalidFormat));
+        assert_eq!(from_ppm(b"P6\n1 1\n255"), Err(TooDeeError::InvalidFormat));
+        assert_eq!(from_ppm(b"P6\n0 1\n255\n"), Err(TooDeeError::InconsistentZeroDimension));
+        assert_eq!(from_ppm(b"P6\n99999999999999999999 1\n255\n"), Err(TooDeeError::InvalidFormat));
+    }
+
+    #[test]
+    fn blend_over_opaque() {
+        let mut bottom = TooDee::from_fn(3, 2, |(c, r)| [c as u8, r as u8, 7, (c * 100) as u8]);
+        let top = TooDee::from_fn(3, 2, |(c, r)| [r as u8, c as u8, 99, 255]);
+        bottom.blend_over(&top);
+        assert_eq!(bottom, top);
+    }
+
+    #[test]
+    fn blend_over_transparent() {
+        let original = TooDee::from_fn(3, 2, |(c, r)| [c as u8, r as u8, 7, 200]);
+        let mut bottom = original.clone();
+        bottom.blend_over(&TooDee::init(3, 2, [255, 255, 255, 0]));
+        assert_eq!(bottom, original);
+    }
+
+    #[test]
+    fn blend_over_half() {
+        let mut bottom = TooDee::from_vec(2, 1, vec![[0u8, 0, 255, 255], [0, 0, 0, 0]]);
+        let top = TooDee::init(4, 2, [255u8, 0, 0, 128]);
+        bottom.blend_over(&top.view((1, 1), (3, 2)));
+        // red at 128/255 over opaque blue
+        assert_eq!(bottom[(0, 0)], [128, 0, 127, 255]);
+        // over a fully transparent pixel, the top pixel is unchanged
+        assert_eq!(bottom[(1, 0)], [255, 0, 0, 128]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch: self is 2x1, other is 1x2")]
+    fn blend_over_mismatch() {
+        let mut bottom = TooDee::init(2, 1, [0u8; 4]);
+        bottom.blend_over(&TooDee::init(1, 2, [0u8; 4]));
+    }
+
+    #[test]
+    fn to_grayscale_primaries() {
+        let toodee = TooDee::from_vec(3, 1, vec![[255u8, 0, 0], [0, 255, 0], [0, 0, 255]]);
+        let gray = toodee.to_grayscale();
+        assert_eq!(gray.size(), (3, 1));
+        assert_eq!(gray.data(), &[76, 150, 29]);
+    }
+
+    #[test]
+    fn to_grayscale_gray() {
+        let toodee = TooDee::from_vec(2, 2, vec![[128u8, 128, 128], [0, 0, 0], [255, 255, 255], [1, 1, 1]]);
+        assert_eq!(toodee.to_grayscale().data(), &[128, 0, 255, 1]);
+    }
+
+    #[test]
+    fn to_grayscale_empty() {
+        let toodee = TooDee::<[u8; 3]>::default();
+        assert_eq!(toodee.to_grayscale().size(), (0, 0));
+    }
+
+    #[test]
+    fn pixelate_gradient() {
+        let mut toodee = TooDee::from_fn(4, 4, |(c, r)| (c * 10 + r * 40) as u8);
+        toodee.pixelate(2);
+        assert_eq!(toodee.data(), &[
+            25, 25, 45, 45,
+            25, 25, 45, 45,
+            105, 105, 125, 125,
+            105, 105, 125, 125,
+        ]);
+    }
+
+    #[test]
+    fn pixelate_partial_blocks() {
+        let mut toodee = TooDee::from_fn(5, 3, |(c, r)| (c * 10 + r * 100) as u8);
+        toodee.pixelate(2);
+        // the right-hand column and bottom row form partial blocks
+        assert_eq!(toodee[0], [55, 55, 75, 75, 90]);
+        assert_eq!(toodee[1], [55, 55, 75, 75, 90]);
+        assert_eq!(toodee[2], [205, 205, 225, 225, 240]);
+    }
+
+    #[test]
+    fn pixelate_rounding() {
+        let mut toodee = TooDee::from_vec(3, 1, vec![0u8, 1, 255]);
+        toodee.pixelate(2);
+        assert_eq!(toodee.data(), &[1, 1, 255]);
+        toodee.pixelate(1);
+        assert_eq!(toodee.data(), &[1, 1, 255]);
+        toodee.pixelate(10);
+        assert_eq!(toodee.data(), &[86, 86, 86]);
+    }
+
+    #[test]
+    #[should_panic(expected = "block size must be non-zero")]
+    fn pixelate_zero() {
+        let mut toodee = TooDee::init(2, 2, 0u8);
+        toodee.pixelate(0);
+    }
+
+    #[test]
+    fn downsample_average_4x4() {
+        let toodee = TooDee::from_vec(4, 4, vec![
+            1u8, 3, 10, 20,
+            5, 7, 30, 40,
+            0, 0, 255, 255,
+            0, 1, 255, 254,
+        ]);
+        let small = toodee.downsample_average(2);
+        assert_eq!(small.size(), (2, 2));
+        assert_eq!(small.data(), &[4, 25, 0, 255]);
+        for (block, &v) in toodee.tiles(2, 2).zip(small.cells()) {
+            let sum : u32 = block.cells().map(|&c| c as u32).sum();
+            assert_eq!(v as u32, (sum + 2) / 4);
+        }
+    }
+
+    #[test]
+    fn downsample_average_partial_blocks() {
+        let toodee = TooDee::from_fn(5, 4, |(c, r)| (c * 10 + r * 100) as u32);
+        let small = toodee.downsample_average(3);
+        assert_eq!(small.size(), (2, 2));
+        // the partial blocks are (2x3), (3x1) and (2x1)
+        assert_eq!(small.data(), &[110, 135, 310, 335]);
+        assert_eq!(toodee.downsample_average(1), toodee);
+        assert_eq!(toodee.downsample_average(100).data(), &[170]);
+    }
+
+    #[test]
+    fn downsample_average_large_values() {
+        let toodee = TooDee::init(3, 3, u64::MAX);
+        assert_eq!(toodee.downsample_average(2).data(), &[u64::MAX; 4]);
+        assert!(TooDee::<u64>::default().downsample_average(2).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "factor must be non-zero")]
+    fn downsample_average_zero() {
+        TooDee::init(2, 2, 0u8).downsample_average(0);
+    }
+
+    #[test]
+    fn sobel_magnitude_vertical_edge() {
+        let toodee = TooDee::from_fn(6, 5, |(col, _)| if col < 3 { 10u8 } else { 200 });
+        let edges = toodee.sobel_magnitude();
+        assert_eq!(edges.size(), (6, 5));
+        for row in edges.rows() {
+            assert_eq!(row, [0, 0, 760, 760, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn sobel_magnitude_diagonal() {
+        // a single bright pixel in the centre
+        let mut toodee = TooDee::init(3, 3, 0u8);
+        toodee[(1, 1)] = 100;
+        let edges = toodee.sobel_magnitude();
+        assert_eq!(edges[(1, 1)], 0);
+        // one of gx/gy is 200, the other 0, at the (edge clamped) orthogonal neighbours
+        assert_eq!(edges[(1, 0)], 200);
+        assert_eq!(edges[(0, 1)], 200);
+        // gx = gy = 100 at the corners
+        assert_eq!(edges[(0, 0)], 141);
+    }
+
+    #[test]
+    fn sobel_magnitude_extremes() {
+        let toodee = TooDee::from_fn(2, 2, |(col, row)| if col == 1 && row == 1 { 255u8 } else { 0 });
+        assert!(toodee.sobel_magnitude().cells().all(|&v| v > 0));
+        let toodee = TooDee::from_fn(3, 3, |(col, row)| if col + row >= 2 { 255u8 } else { 0 });
+        assert_eq!(toodee.sobel_magnitude()[(1, 1)], 1082);
+        assert!(TooDee::<u8>::default().sobel_magnitude().is_empty());
+        assert!(TooDee::init(4, 4, 255u8).sobel_magnitude().cells().all(|&v| v == 0));
+    }
+}
@@ -201,4 +201,451 @@ mod toodee_tests_iter {
         assert_eq!(iter.len(), 130);
         assert_eq!(iter.num_cols(), 10);
     }
+
+    #[test]
+    fn group_consecutive_rows() {
+        let toodee = TooDee::from_vec(3, 6, vec![
+            1, 2, 3,
+            1, 2, 3,
+            1, 2, 3,
+            4, 5, 6,
+            1, 2, 3,
+            1, 2, 3,
+        ]);
+        let groups : Vec<_> = toodee.group_consecutive_rows().collect();
+        assert_eq!(groups, vec![(&[1, 2, 3][..], 3), (&[4, 5, 6][..], 1), (&[1, 2, 3][..], 2)]);
+        let view = toodee.view((1, 2), (3, 6));
+        let counts : Vec<_> = view.group_consecutive_rows().map(|(_, n)| n).collect();
+        assert_eq!(counts, vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn group_consecutive_rows_empty() {
+        let toodee : TooDee<u32> = TooDee::default();
+        assert_eq!(toodee.group_consecutive_rows().next(), None);
+        let toodee = TooDee::init(4, 3, 7u32);
+        assert_eq!(toodee.group_consecutive_rows().collect::<Vec<_>>(), vec![(&[7, 7, 7, 7][..], 3)]);
+    }
+
+    #[test]
+    fn cells_contiguous_matches_rows() {
+        let mut toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        for (start, end) in [((0, 0), (5, 4)), ((0, 1), (5, 3)), ((1, 1), (4, 4)), ((0, 2), (5, 3))] {
+            let view = toodee.view(start, end);
+            let expected : Vec<u32> = view.rows().flatten().copied().collect();
+            assert_eq!(view.cells().copied().collect::<Vec<_>>(), expected);
+            assert_eq!(view.cells().rev().copied().collect::<Vec<_>>(), expected.iter().rev().copied().collect::<Vec<_>>());
+            assert_eq!(view.cells().len(), expected.len());
+            let mut cells = view.cells();
+            let mut reference = view.rows().flatten();
+            assert_eq!(cells.nth(1), reference.nth(1));
+            assert_eq!(cells.nth_back(2), reference.nth_back(2));
+            assert_eq!(cells.len(), expected.len() - 5);
+            assert_eq!(cells.next_back(), reference.next_back());
+            assert_eq!(cells.next(), reference.next());
+            let mut view = toodee.view_mut(start, end);
+            assert_eq!(view.cells_mut().map(|v| *v).collect::<Vec<_>>(), expected);
+            let mut cells = view.cells_mut();
+            assert_eq!(cells.nth(1).copied(), expected.get(1).copied());
+            assert_eq!(cells.len(), expected.len() - 2);
+        }
+        for v in toodee.cells_mut() {
+            *v += 1;
+        }
+        assert_eq!(toodee.data(), (1u32..21).collect::<Vec<_>>().as_slice());
+        let view = toodee.view((0, 1), (5, 3));
+        let mut indexed = view.indexed_cells_parent().skip(4);
+        assert_eq!(indexed.next(), Some(((4, 1), &10)));
+        assert_eq!(indexed.next(), Some(((0, 2), &11)));
+    }
+
+    #[test]
+    fn diagonals() {
+        let toodee = TooDee::from_vec(3, 2, (0u32..6).collect());
+        let mut diagonals = toodee.diagonals();
+        assert_eq!(diagonals.len(), 4);
+        assert_eq!(diagonals.next().unwrap().copied().collect::<Vec<_>>(), vec![0]);
+        assert_eq!(diagonals.next().unwrap().copied().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(diagonals.next_back().unwrap().copied().collect::<Vec<_>>(), vec![5]);
+        assert_eq!(diagonals.next().unwrap().copied().collect::<Vec<_>>(), vec![2, 4]);
+        assert!(diagonals.next().is_none());
+        assert!(diagonals.next_back().is_none());
+    }
+
+    #[test]
+    fn diagonals_cover_all_cells() {
+        let toodee = TooDee::from_vec(7, 5, (0u32..35).collect());
+        for (start, end) in [((0, 0), (7, 5)), ((1, 1), (3, 5)), ((2, 0), (7, 2)), ((3, 0), (4, 5)), ((0, 4), (7, 5))] {
+            let view = toodee.view(start, end);
+            let (num_cols, num_rows) = view.size();
+            assert_eq!(view.diagonals().len(), num_cols + num_rows - 1);
+            let mut count = 0;
+            for (k, diagonal) in view.diagonals().enumerate() {
+                for v in diagonal {
+                    let (col, row) = ((*v % 7) as usize - start.0, (*v / 7) as usize - start.1);
+                    assert_eq!(col + row, k);
+                    count += 1;
+                }
+            }
+            assert_eq!(count, num_cols * num_rows);
+        }
+    }
+
+    #[test]
+    fn diagonals_empty() {
+        let toodee : TooDee<u32> = TooDee::default();
+        assert_eq!(toodee.diagonals().len(), 0);
+        assert!(toodee.diagonals().next().is_none());
+    }
+
+    #[test]
+    fn neighbors8_wrapping_corner() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let neighbours : Vec<_> = toodee.neighbors8_wrapping((0, 0)).collect();
+        assert_eq!(neighbours, vec![
+            ((3, 2), &11), ((0, 2), &8), ((1, 2), &9),
+            ((3, 0), &3), ((1, 0), &1),
+            ((3, 1), &7), ((0, 1), &4), ((1, 1), &5),
+        ]);
+        let coords : Vec<_> = toodee.neighbors8_wrapping((3, 2)).rev().map(|(c, _)| c).collect();
+        assert_eq!(coords, vec![(0, 0), (3, 0), (2, 0), (0, 2), (2, 2), (0, 1), (3, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn neighbors8_wrapping_view() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let view = toodee.view((1, 1), (3, 3));
+        let values : Vec<u32> = view.neighbors8_wrapping((1, 0)).map(|(_, &v)| v).collect();
+        assert_eq!(values, vec![9, 10, 9, 5, 5, 9, 10, 9]);
+        assert_eq!(view.neighbors8_wrapping((1, 1)).len(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn neighbors8_wrapping_out_of_bounds() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        toodee.neighbors8_wrapping((0, 3));
+    }
+
+    #[test]
+    fn cols() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let cols = toodee.cols();
+        assert_eq!(cols.len(), 4);
+        let cols : Vec<Vec<u32>> = cols.map(|c| c.copied().collect()).collect();
+        assert_eq!(cols, toodee.columns_to_vecs());
+        let firsts : Vec<u32> = toodee.cols().rev().map(|c| c[0]).collect();
+        assert_eq!(firsts, vec![3, 2, 1, 0]);
+        let view = toodee.view((1, 1), (3, 3));
+        let cols : Vec<Vec<u32>> = view.cols().map(|c| c.copied().collect()).collect();
+        assert_eq!(cols, vec![vec![5, 9], vec![6, 10]]);
+    }
+
+    #[test]
+    fn cols_double_ended() {
+        let toodee = TooDee::from_vec(3, 2, (0u32..6).collect());
+        let mut cols = toodee.cols();
+        assert_eq!(cols.next().unwrap().copied().collect::<Vec<_>>(), vec![0, 3]);
+        assert_eq!(cols.next_back().unwrap().copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(cols.len(), 1);
+        assert_eq!(cols.next_back().unwrap().copied().collect::<Vec<_>>(), vec![1, 4]);
+        assert!(cols.next().is_none());
+        assert!(cols.next_back().is_none());
+    }
+
+    #[test]
+    fn cols_mut() {
+        let mut toodee = TooDee::init(4, 3, 0u32);
+        let mut view = toodee.view_mut((1, 0), (4, 2));
+        let mut cols = view.cols_mut();
+        assert_eq!(cols.len(), 3);
+        let mut first = cols.next().unwrap();
+        let mut last = cols.next_back().unwrap();
+        first[1] = 1;
+        last[0] = 2;
+        cols.for_each(|c| c.for_each(|v| *v = 3));
+        assert_eq!(toodee.data(), &[
+            0, 0, 3, 2,
+            0, 1, 3, 0,
+            0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn cols_empty() {
+        let mut toodee : TooDee<u32> = TooDee::default();
+        assert_eq!(toodee.cols().len(), 0);
+        assert!(toodee.cols().next().is_none());
+        assert!(toodee.cols_mut().next_back().is_none());
+    }
+
+    #[test]
+    fn diagonal() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let diagonal = toodee.diagonal();
+        assert_eq!(diagonal.len(), 3);
+        assert_eq!(diagonal.copied().collect::<Vec<_>>(), vec![0, 5, 10]);
+        assert_eq!(toodee.diagonal().rev().copied().collect::<Vec<_>>(), vec![10, 5, 0]);
+        let tall = TooDee::from_vec(2, 5, (0u32..10).collect());
+        assert_eq!(tall.diagonal().copied().collect::<Vec<_>>(), vec![0, 3]);
+        let view = toodee.view((1, 1), (4, 3));
+        assert_eq!(view.diagonal().copied().collect::<Vec<_>>(), vec![5, 10]);
+        assert_eq!(toodee.diagonal()[2], 10);
+    }
+
+    #[test]
+    fn diagonal_mut() {
+        let mut toodee = TooDee::init(5, 4, 0u32);
+        toodee.view_mut((2, 1), (5, 4)).diagonal_mut().for_each(|v| *v = 1);
+        assert_eq!(toodee.data(), &[
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 1, 0,
+            0, 0, 0, 0, 1,
+        ]);
+        let mut diagonal = toodee.diagonal_mut();
+        assert_eq!(diagonal.len(), 4);
+        diagonal[3] = 7;
+        assert_eq!(toodee[(3, 3)], 7);
+    }
+
+    #[test]
+    fn diagonal_empty() {
+        let mut toodee : TooDee<u32> = TooDee::default();
+        assert_eq!(toodee.diagonal().len(), 0);
+        assert!(toodee.diagonal_mut().next().is_none());
+    }
+
+    #[test]
+    fn windows_2d() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let windows = toodee.windows_2d(2, 2);
+        assert_eq!(windows.len(), 3 * 2);
+        let corners : Vec<Coordinate> = windows.map(|w| w.parent_coord((0, 0))).collect();
+        assert_eq!(corners, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        let last = toodee.windows_2d(2, 2).next_back().unwrap();
+        assert_eq!(last.size(), (2, 2));
+        assert_eq!(last.cells().copied().collect::<Vec<_>>(), vec![6, 7, 10, 11]);
+        assert_eq!(toodee.windows_2d(4, 3).count(), 1);
+        assert_eq!(toodee.windows_2d(1, 1).count(), 12);
+        let mut windows = toodee.windows_2d(1, 3);
+        assert_eq!(windows.nth(2).unwrap()[(0, 2)], 10);
+        assert_eq!(windows.len(), 1);
+        assert!(windows.nth(5).is_none());
+    }
+
+    #[test]
+    fn windows_2d_view() {
+        let toodee = TooDee::from_vec(5, 5, (0u32..25).collect());
+        let view = toodee.view((1, 1), (4, 4));
+        let windows : Vec<_> = view.windows_2d(3, 2).collect();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[1].cells().copied().collect::<Vec<_>>(), vec![11, 12, 13, 16, 17, 18]);
+        assert_eq!(windows[1].parent_coord((0, 0)), (1, 2));
+    }
+
+    #[test]
+    fn windows_2d_too_large() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        assert_eq!(toodee.windows_2d(5, 1).len(), 0);
+        assert!(toodee.windows_2d(1, 4).next().is_none());
+        let empty = TooDee::<u32>::default();
+        assert_eq!(empty.windows_2d(1, 1).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window dimensions must be non-zero")]
+    fn windows_2d_zero() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        toodee.windows_2d(0, 1);
+    }
+
+    #[test]
+    fn tiles_exact() {
+        let toodee = TooDee::from_vec(4, 4, (0u32..16).collect());
+        let tiles : Vec<_> = toodee.tiles(2, 2).collect();
+        assert_eq!(tiles.len(), 4);
+        assert!(tiles.iter().all(|t| t.size() == (2, 2)));
+        let corners : Vec<Coordinate> = tiles.iter().map(|t| t.parent_coord((0, 0))).collect();
+        assert_eq!(corners, vec![(0, 0), (2, 0), (0, 2), (2, 2)]);
+        assert_eq!(tiles[3].cells().copied().collect::<Vec<_>>(), vec![10, 11, 14, 15]);
+        assert_eq!(tiles.iter().map(|t| t.cells().count()).sum::<usize>(), 16);
+    }
+
+    #[test]
+    fn tiles_clipped() {
+        let toodee = TooDee::from_vec(5, 3, (0u32..15).collect());
+        let tiles = toodee.tiles(2, 2);
+        assert_eq!(tiles.len(), 6);
+        let sizes : Vec<_> = tiles.map(|t| t.size()).collect();
+        assert_eq!(sizes, vec![(2, 2), (2, 2), (1, 2), (2, 1), (2, 1), (1, 1)]);
+        let last = toodee.tiles(2, 2).next_back().unwrap();
+        assert_eq!(last.cells().copied().collect::<Vec<_>>(), vec![14]);
+        assert_eq!(toodee.tiles(10, 10).next().unwrap().size(), (5, 3));
+        let mut tiles = toodee.tiles(2, 2);
+        assert_eq!(tiles.nth(4).unwrap()[(0, 0)], 12);
+        assert!(tiles.nth(1).is_none());
+        assert_eq!(tiles.len(), 0);
+    }
+
+    #[test]
+    fn tiles_view() {
+        let toodee = TooDee::from_vec(5, 5, (0u32..25).collect());
+        let view = toodee.view((1, 1), (4, 4));
+        let tiles : Vec<_> = view.tiles(2, 3).collect();
+        assert_eq!(tiles.len(), 2);
+        assert_eq!(tiles[1].cells().copied().collect::<Vec<_>>(), vec![8, 13, 18]);
+        assert_eq!(tiles[1].parent_coord((0, 0)), (3, 1));
+        assert_eq!(TooDee::<u32>::default().tiles(1, 1).len(), 0);
+    }
+
+    #[test]
+    fn tiles_mut() {
+        let mut toodee = TooDee::init(5, 3, 0u32);
+        // hold all tiles at once, to confirm they are independent
+        let mut tiles : Vec<_> = toodee.tiles_mut(2, 2).collect();
+        assert_eq!(tiles.len(), 6);
+        for (i, tile) in tiles.iter_mut().enumerate() {
+            tile.fill(i as u32);
+        }
+        assert_eq!(toodee.data(), &[
+            0, 0, 1, 1, 2,
+            0, 0, 1, 1, 2,
+            3, 3, 4, 4, 5,
+        ]);
+        let mut view = toodee.view_mut((1, 0), (5, 3));
+        for mut tile in view.tiles_mut(3, 2).rev() {
+            tile[(0, 0)] = 9;
+        }
+        assert_eq!(toodee[0], [0, 9, 1, 1, 9]);
+        assert_eq!(toodee[2], [3, 9, 4, 4, 9]);
+    }
+
+    #[test]
+    fn tiles_mut_partial_edges() {
+        let mut toodee = TooDee::init(10, 10, usize::MAX);
+        let tiles = toodee.tiles_mut(3, 3);
+        assert_eq!(tiles.len(), 16);
+        for (i, mut tile) in tiles.enumerate() {
+            tile.fill(i);
+        }
+        // every cell is written exactly once, by the tile that contains it
+        for ((col, row), &v) in toodee.cells_with_coords() {
+            assert_eq!(v, (row / 3) * 4 + col / 3);
+        }
+    }
+
+    #[test]
+    fn tiles_mut_threads() {
+        let mut toodee = TooDee::init(10, 10, 0u32);
+        std::thread::scope(|s| {
+            for (i, mut tile) in toodee.tiles_mut(3, 3).enumerate() {
+                s.spawn(move || tile.cells_mut().for_each(|v| *v += i as u32 + 1));
+            }
+        });
+        assert!(toodee.tiles(3, 3).enumerate().all(|(i, t)| t.cells().all(|&v| v == i as u32 + 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "tile dimensions must be non-zero")]
+    fn tiles_zero() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        toodee.tiles(1, 0);
+    }
+
+    #[test]
+    fn cells_with_coords() {
+        let toodee = TooDee::from_vec(3, 2, (0u32..6).collect());
+        let cells : Vec<_> = toodee.cells_with_coords().collect();
+        assert_eq!(cells, vec![((0, 0), &0), ((1, 0), &1), ((2, 0), &2), ((0, 1), &3), ((1, 1), &4), ((2, 1), &5)]);
+        assert_eq!(toodee.cells_with_coords().len(), 6);
+        assert_eq!(toodee.cells_with_coords().next_back(), Some(((2, 1), &5)));
+        assert_eq!(TooDee::<u32>::default().cells_with_coords().next(), None);
+    }
+
+    #[test]
+    fn cells_with_coords_view() {
+        let toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        let view = toodee.view((1, 1), (4, 4));
+        for ((col, row), v) in view.cells_with_coords() {
+            assert_eq!(*v, view[(col, row)]);
+        }
+        // view coordinates are local, unlike `indexed_cells_parent()`
+        let local : Vec<_> = view.cells_with_coords().map(|(c, _)| c).collect();
+        let parent : Vec<_> = view.indexed_cells_parent().map(|(c, _)| c).collect();
+        assert_eq!(local.iter().map(|&c| view.parent_coord(c)).collect::<Vec<_>>(), parent);
+    }
+
+    #[test]
+    fn cells_with_coords_mut() {
+        let mut toodee = TooDee::init(5, 4, (0usize, 0usize));
+        let mut view = toodee.view_mut((1, 1), (4, 4));
+        let mut iter = view.cells_with_coords_mut();
+        assert_eq!(iter.len(), 9);
+        let ((col, row), last) = iter.next_back().unwrap();
+        assert_eq!((col, row), (2, 2));
+        *last = (99, 99);
+        for (coord, v) in iter {
+            *v = coord;
+        }
+        assert_eq!(toodee[(2, 3)], (1, 2));
+        assert_eq!(toodee[(3, 3)], (99, 99));
+        assert_eq!(toodee[(4, 3)], (0, 0));
+    }
+
+    #[test]
+    fn hilbert_cells_4x4() {
+        let toodee = TooDee::from_vec(4, 4, (0u32..16).collect());
+        let coords : Vec<Coordinate> = toodee.hilbert_cells().map(|(c, _)| c).collect();
+        assert_eq!(coords, vec![
+            (0, 0), (1, 0), (1, 1), (0, 1),
+            (0, 2), (0, 3), (1, 3), (1, 2),
+            (2, 2), (2, 3), (3, 3), (3, 2),
+            (3, 1), (2, 1), (2, 0), (3, 0),
+        ]);
+        for (coord, &v) in toodee.hilbert_cells() {
+            assert_eq!(v, toodee[coord]);
+        }
+        let mut reversed : Vec<Coordinate> = toodee.hilbert_cells().rev().map(|(c, _)| c).collect();
+        reversed.reverse();
+        assert_eq!(reversed, coords);
+    }
+
+    #[test]
+    fn hilbert_cells_visits_once() {
+        let toodee = TooDee::from_vec(32, 32, (0u32..1024).collect());
+        let mut visited = TooDee::init(32, 32, 0u32);
+        let mut prev : Option<Coordinate> = None;
+        for ((col, row), _) in toodee.hilbert_cells() {
+            visited[(col, row)] += 1;
+            // consecutive cells are adjacent
+            if let Some((pc, pr)) = prev {
+                assert_eq!(pc.abs_diff(col) + pr.abs_diff(row), 1);
+            }
+            prev = Some((col, row));
+        }
+        assert!(visited.cells().all(|&v| v == 1));
+    }
+
+    #[test]
+    fn hilbert_cells_view() {
+        let toodee = TooDee::from_vec(5, 5, (0u32..25).collect());
+        let view = toodee.view((1, 1), (3, 3));
+        let cells : Vec<u32> = view.hilbert_cells().map(|(_, &v)| v).collect();
+        assert_eq!(cells, vec![6, 11, 12, 7]);
+        let mut iter = view.hilbert_cells();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.nth(2), Some(((1, 1), &12)));
+        assert_eq!(iter.len(), 1);
+    }
+
+    #[test]
+    fn hilbert_cells_fallback() {
+        let toodee = TooDee::from_vec(3, 3, (0u32..9).collect());
+        assert!(toodee.hilbert_cells().eq(toodee.cells_with_coords()));
+        let toodee = TooDee::from_vec(4, 2, (0u32..8).collect());
+        assert!(toodee.hilbert_cells().eq(toodee.cells_with_coords()));
+        assert_eq!(TooDee::init(1, 1, 7u32).hilbert_cells().next(), Some(((0, 0), &7)));
+        assert_eq!(TooDee::<u32>::default().hilbert_cells().next(), None);
+    }
 }
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod toodee_tests_sat {
+
+    use crate::*;
+
+    fn brute_force_sum(toodee: &TooDee<u32>, top_left: Coordinate, bottom_right: Coordinate) -> u32 {
+        toodee.view(top_left, (bottom_right.0 + 1, bottom_right.1 + 1)).cells().sum()
+    }
+
+    #[test]
+    fn table() {
+        let toodee = TooDee::from_vec(3, 3, (1u32..=9).collect());
+        let sat = SatGrid::new(&toodee);
+        assert_eq!(sat.table().data(), &[1, 3, 6, 5, 12, 21, 12, 27, 45]);
+        assert_eq!(sat.into_inner().size(), (3, 3));
+    }
+
+    #[test]
+    fn region_sum_matches_brute_force() {
+        let toodee = TooDee::from_fn(7, 5, |(c, r)| ((c * 31 + r * 17) % 11) as u32);
+        let sat = SatGrid::new(&toodee);
+        for top in 0..5 {
+            for left in 0..7 {
+                for bottom in top..5 {
+                    for right in left..7 {
+                        assert_eq!(sat.region_sum((left, top), (right, bottom)),
+                            brute_force_sum(&toodee, (left, top), (right, bottom)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn region_sum_view() {
+        let toodee = TooDee::from_vec(4, 4, (0i64..16).collect());
+        let view = toodee.view((1, 1), (4, 3));
+        let sat = SatGrid::new(&view);
+        assert_eq!(sat.table().size(), (3, 2));
+        assert_eq!(sat.region_sum((0, 0), (2, 1)), 5 + 6 + 7 + 9 + 10 + 11);
+        assert_eq!(sat.region_sum((2, 1), (2, 1)), 11);
+    }
+
+    #[test]
+    fn region_sum_signed() {
+        let toodee = TooDee::from_vec(3, 2, vec![-1.5f64, 2.0, -3.0, 4.0, -5.0, 6.5]);
+        let sat = SatGrid::new(&toodee);
+        assert_eq!(sat.region_sum((1, 0), (2, 1)), 0.5);
+        assert_eq!(sat.region_sum((0, 0), (2, 1)), 3.0);
+    }
+
+    #[test]
+    fn empty() {
+        let sat = SatGrid::new(&TooDee::<u32>::default());
+        assert!(sat.table().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "region is out of bounds")]
+    fn region_sum_out_of_bounds() {
+        let sat = SatGrid::new(&TooDee::init(3, 2, 1u32));
+        sat.region_sum((0, 0), (2, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "region is out of bounds")]
+    fn region_sum_inverted() {
+        let sat = SatGrid::new(&TooDee::init(3, 2, 1u32));
+        sat.region_sum((2, 0), (1, 1));
+    }
+}
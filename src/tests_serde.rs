@@ -72,6 +72,20 @@ mod toodee_tests_serde {
         let _: TooDee<u32> = serde_json::from_str(JSON_OVERFLOW_ARRAY).unwrap();
     }
 
+    const JSON_ZERO_ROWS: &str = r#"
+{
+  "num_rows": 0,
+  "num_cols": 3,
+  "data": []
+}
+"#;
+
+    #[test]
+    #[should_panic(expected = "invalid value: zero dimension, expected both dimensions to be zero, or neither")]
+    fn deserialize_inconsistent_zero_dimension() {
+        let _: TooDee<u32> = serde_json::from_str(JSON_ZERO_ROWS).unwrap();
+    }
+
     const JSON_NEGATIVE_DIMENSIONS: &str = r#"
 {
   "num_rows": -1,
@@ -125,4 +139,110 @@ mod toodee_tests_serde {
         assert_eq!(deser.data().len(), 8);
         assert_eq!(deser.data(), &[6, 7, 11, 12, 16, 17, 21, 22]);
     }
+
+    #[test]
+    fn serde_view_full() {
+        let tmp = new_5_by_10();
+        let view = tmp.view((0, 0), (5, 10));
+        assert_eq!(serde_json::to_string(&view).unwrap(), serde_json::to_string(&tmp).unwrap());
+    }
+
+    #[test]
+    fn serialize_field_order() {
+        let tmp = TooDee::from_vec(2, 1, vec![1u32, 2]);
+        assert_eq!(serde_json::to_string(&tmp).unwrap(), r#"{"num_cols":2,"num_rows":1,"data":[1,2]}"#);
+    }
+
+    #[test]
+    fn serde_nested_view() {
+        let tmp = new_5_by_10();
+        let outer = tmp.view((1, 2), (5, 9));
+        let inner = outer.view((1, 1), (3, 4));
+        let serialized = serde_json::to_string(&inner).unwrap();
+        assert_eq!(serialized, r#"{"num_cols":2,"num_rows":3,"data":[17,18,22,23,27,28]}"#);
+        let deser: TooDee<u32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deser, inner.into());
+        assert_eq!(serde_json::to_string(&deser).unwrap(), serialized);
+    }
+
+    #[test]
+    fn serde_view_f64() {
+        let tmp = TooDee::from_vec(3, 2, vec![0.5f64, 1.5, 2.5, 3.5, 4.5, 5.5]);
+        let view: TooDeeView<'_, f64> = tmp.view((1, 0), (3, 2));
+        let serialized = serde_json::to_string(&view).unwrap();
+        assert_eq!(serialized, r#"{"num_cols":2,"num_rows":2,"data":[1.5,2.5,4.5,5.5]}"#);
+        let deser: TooDee<f64> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deser, view.into());
+    }
+
+    #[test]
+    fn serde_view_string() {
+        let mut tmp = TooDee::from_vec(2, 2, vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()]);
+        let view: TooDeeView<'_, String> = tmp.view((0, 1), (2, 2));
+        let serialized = serde_json::to_string(&view).unwrap();
+        assert_eq!(serialized, r#"{"num_cols":2,"num_rows":1,"data":["c","d"]}"#);
+        let view_mut: TooDeeViewMut<'_, String> = tmp.view_mut((1, 0), (2, 2));
+        let deser: TooDee<String> = serde_json::from_str(&serde_json::to_string(&view_mut).unwrap()).unwrap();
+        assert_eq!(deser.data(), &["b", "d"]);
+    }
+
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct RleGrid {
+        #[serde(serialize_with = "crate::serialize_rle", deserialize_with = "crate::deserialize_rle")]
+        grid: TooDee<u32>,
+    }
+
+    fn mostly_uniform() -> TooDee<u32> {
+        let mut toodee = TooDee::init(64, 32, 0u32);
+        toodee.view_mut((10, 5), (30, 20)).fill(7);
+        toodee[(63, 31)] = 1;
+        toodee
+    }
+
+    #[test]
+    fn serde_rle_round_trip() {
+        let rle = RleGrid { grid: mostly_uniform() };
+        let serialized = serde_json::to_string(&rle).unwrap();
+        let deser: RleGrid = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deser.grid, rle.grid);
+        let flat = serde_json::to_string(&rle.grid).unwrap();
+        assert!(serialized.len() * 10 < flat.len());
+    }
+
+    #[test]
+    fn serde_rle_view() {
+        let tmp = new_5_by_10();
+        let view = tmp.view((1, 1), (3, 5));
+        let mut json = Vec::new();
+        crate::serialize_rle(&view, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let deser: TooDee<u32> = crate::deserialize_rle(&mut serde_json::Deserializer::from_slice(&json)).unwrap();
+        assert_eq!(deser, view.into());
+    }
+
+    #[test]
+    fn serde_rle_empty() {
+        let rle = RleGrid { grid: TooDee::default() };
+        let serialized = serde_json::to_string(&rle).unwrap();
+        assert_eq!(serialized, r#"{"grid":{"num_cols":0,"num_rows":0,"runs":[]}}"#);
+        let deser: RleGrid = serde_json::from_str(&serialized).unwrap();
+        assert!(deser.grid.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value: run lengths, expected run lengths to sum to num_cols * num_rows")]
+    fn deserialize_rle_bad_runs() {
+        let _: RleGrid = serde_json::from_str(r#"{"grid":{"num_cols":2,"num_rows":2,"runs":[[3,1]]}}"#).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value: run lengths")]
+    fn deserialize_rle_overflowing_runs() {
+        let _: RleGrid = serde_json::from_str(r#"{"grid":{"num_cols":2,"num_rows":2,"runs":[[18446744073709551615,1],[5,2]]}}"#).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "one dimension is zero but the other is non-zero")]
+    fn deserialize_rle_inconsistent_zero_dimension() {
+        let _: RleGrid = serde_json::from_str(r#"{"grid":{"num_cols":0,"num_rows":2,"runs":[]}}"#).unwrap();
+    }
 }
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod toodee_tests_shuffle {
+
+    use crate::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn sorted_rows(toodee: &TooDee<u32>) -> Vec<Vec<u32>> {
+        let mut rows : Vec<Vec<u32>> = toodee.rows().map(|r| r.to_vec()).collect();
+        rows.sort();
+        rows
+    }
+
+    #[test]
+    fn shuffle_rows() {
+        let original = TooDee::from_vec(4, 20, (0u32..80).collect());
+        let mut a = original.clone();
+        a.shuffle_rows(&mut StdRng::seed_from_u64(42));
+        let mut b = original.clone();
+        b.shuffle_rows(&mut StdRng::seed_from_u64(42));
+        assert_eq!(a, b);
+        assert_ne!(a, original);
+        assert_eq!(sorted_rows(&a), sorted_rows(&original));
+    }
+
+    #[test]
+    fn shuffle_cols() {
+        let original = TooDee::from_vec(20, 4, (0u32..80).collect());
+        let mut a = original.clone();
+        a.shuffle_cols(&mut StdRng::seed_from_u64(7));
+        let mut b = original.clone();
+        b.shuffle_cols(&mut StdRng::seed_from_u64(7));
+        assert_eq!(a, b);
+        assert_ne!(a, original);
+        // each column is moved as a whole
+        for row in a.rows() {
+            for (v, first) in row.iter().zip(&a[0]) {
+                assert_eq!(v % 20, *first);
+            }
+        }
+        let mut transposed = a.clone();
+        transposed.transpose_blocked(4);
+        let mut expected = original.clone();
+        expected.transpose_blocked(4);
+        assert_eq!(sorted_rows(&transposed), sorted_rows(&expected));
+    }
+
+    #[test]
+    fn shuffle_view() {
+        let mut toodee = TooDee::from_vec(6, 6, (0u32..36).collect());
+        toodee.view_mut((1, 1), (5, 5)).shuffle_rows(&mut StdRng::seed_from_u64(1));
+        assert_eq!(toodee[0], [0, 1, 2, 3, 4, 5]);
+        assert_eq!(toodee[5], [30, 31, 32, 33, 34, 35]);
+        assert!(toodee.rows().all(|r| r[0] % 6 == 0 && r[5] == r[0] + 5));
+        let mut empty : TooDee<u32> = TooDee::default();
+        empty.shuffle_rows(&mut StdRng::seed_from_u64(1));
+        empty.shuffle_cols(&mut StdRng::seed_from_u64(1));
+        assert!(empty.is_empty());
+    }
+}
@@ -61,5 +61,183 @@ mod toodee_tests_sort {
         }
     }
 
-    
+
+    #[test]
+    fn sort_each_row() {
+        let mut toodee = TooDee::from_vec(4, 3, vec![3u32, 1, 2, 0, 9, 8, 7, 6, 5, 5, 4, 6]);
+        toodee.sort_each_row();
+        assert_eq!(toodee.data(), &[0, 1, 2, 3, 6, 7, 8, 9, 4, 5, 5, 6]);
+        toodee.sort_each_row_by(|a, b| b.cmp(a));
+        assert_eq!(toodee.data(), &[3, 2, 1, 0, 9, 8, 7, 6, 6, 5, 5, 4]);
+    }
+
+    #[test]
+    fn sort_each_row_view() {
+        let mut toodee = TooDee::from_vec(4, 3, vec![3u32, 1, 2, 0, 9, 8, 7, 6, 5, 5, 4, 6]);
+        toodee.view_mut((1, 1), (4, 3)).sort_each_row();
+        assert_eq!(toodee.data(), &[3, 1, 2, 0, 9, 6, 7, 8, 5, 4, 5, 6]);
+    }
+
+    #[test]
+    fn sort_each_row_stable() {
+        let mut toodee = TooDee::from_vec(4, 1, vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]);
+        toodee.sort_each_row_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(toodee.data(), &[(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+    }
+
+    #[test]
+    fn sort_each_col() {
+        let rng = rand::thread_rng();
+        let samples = rng.sample_iter(Uniform::new(0,100));
+        let mut toodee = TooDee::from_vec(10, 10, samples.take(100).collect());
+        let original = toodee.clone();
+        toodee.sort_each_col();
+        for col in 0..10 {
+            let mut expected : Vec<u32> = original.col(col).copied().collect();
+            expected.sort();
+            assert_eq!(toodee.col(col).copied().collect::<Vec<u32>>(), expected);
+        }
+    }
+
+    #[test]
+    fn sort_each_col_view() {
+        let mut toodee = TooDee::from_vec(3, 4, vec![
+            (9, 'a'), (5, 'b'), (1, 'c'),
+            (8, 'd'), (5, 'e'), (2, 'f'),
+            (7, 'g'), (4, 'h'), (3, 'i'),
+            (6, 'j'), (4, 'k'), (0, 'l'),
+        ]);
+        toodee.view_mut((1, 0), (3, 4)).sort_each_col_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(toodee.col(0).map(|c| c.1).collect::<String>(), "adgj");
+        // stable: equal keys keep their original order
+        assert_eq!(toodee.col(1).map(|c| c.1).collect::<String>(), "hkbe");
+        assert_eq!(toodee.col(2).map(|c| c.1).collect::<String>(), "lcfi");
+    }
+
+    #[test]
+    fn sort_rows() {
+        let mut toodee = TooDee::from_vec(3, 5, vec![
+            2u32, 0, 1,
+            1, 9, 9,
+            2, 0, 0,
+            0, 5, 5,
+            1, 9, 8,
+        ]);
+        toodee.sort_rows();
+        assert_eq!(toodee.data(), &[
+            0, 5, 5,
+            1, 9, 8,
+            1, 9, 9,
+            2, 0, 0,
+            2, 0, 1,
+        ]);
+    }
+
+    #[test]
+    fn sort_rows_random() {
+        let rng = rand::thread_rng();
+        let samples = rng.sample_iter(Uniform::new(0,4));
+        let mut toodee : TooDee<u32> = TooDee::from_vec(3, 50, samples.take(150).collect());
+        let mut expected : Vec<Vec<u32>> = toodee.rows().map(|r| r.to_vec()).collect();
+        expected.sort();
+        toodee.sort_rows();
+        assert!(toodee.rows().zip(&expected).all(|(r, e)| r == e.as_slice()));
+    }
+
+    #[test]
+    fn sort_rows_by_view_stable() {
+        let mut toodee = TooDee::from_vec(3, 4, vec![
+            9, 3, 0,
+            9, 1, 1,
+            9, 3, 2,
+            9, 1, 3,
+        ]);
+        // sort by the middle column only, within a view that excludes the first column
+        toodee.view_mut((1, 0), (3, 4)).sort_rows_by(|a, b| a[0].cmp(&b[0]));
+        assert_eq!(toodee.data(), &[
+            9, 1, 1,
+            9, 1, 3,
+            9, 3, 0,
+            9, 3, 2,
+        ]);
+        toodee.sort_rows_by(|a, b| b[2].cmp(&a[2]));
+        assert_eq!(toodee.col(2).copied().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn sort_by_col_key() {
+        // sorting rows by column 1 differs from sorting columns by row 1
+        let mut toodee = TooDee::from_vec(3, 3, vec![
+            0u32, 5, 1,
+            2, 3, 9,
+            4, 1, 7,
+        ]);
+        toodee.sort_by_col_key(1, |&v| v);
+        assert_eq!(toodee.data(), &[
+            4, 1, 7,
+            2, 3, 9,
+            0, 5, 1,
+        ]);
+        toodee.sort_by_col_key(2, |&v| core::cmp::Reverse(v));
+        assert_eq!(toodee.col(2).copied().collect::<Vec<_>>(), vec![9, 7, 1]);
+    }
+
+    #[test]
+    fn sort_unstable_by_col_key() {
+        let mut toodee = TooDee::from_vec(3, 3, vec![
+            0u32, 5, 1,
+            2, 3, 9,
+            4, 1, 7,
+        ]);
+        toodee.view_mut((0, 0), (3, 3)).sort_unstable_by_col_key(1, |&v| v);
+        assert_eq!(toodee[0], [4, 1, 7]);
+        assert_eq!(toodee[1], [2, 3, 9]);
+        assert_eq!(toodee[2], [0, 5, 1]);
+    }
+
+    #[test]
+    fn argsort_by_col() {
+        let toodee = TooDee::from_vec(2, 5, vec![
+            3u32, 0,
+            1, 1,
+            4, 2,
+            1, 3,
+            0, 4,
+        ]);
+        let original = toodee.clone();
+        let perm = toodee.argsort_by_col(0, |a, b| a.cmp(b));
+        // stable: the rows with equal keys keep their relative order
+        assert_eq!(perm, vec![4, 1, 3, 0, 2]);
+        assert_eq!(toodee, original);
+        let mut sorted = toodee.clone();
+        sorted.sort_by_col(0, |a, b| a.cmp(b));
+        let mut applied = toodee.clone();
+        applied.apply_row_permutation(&perm);
+        assert_eq!(applied, sorted);
+    }
+
+    #[test]
+    fn argsort_parallel_arrays() {
+        let keys = TooDee::from_vec(1, 4, vec![30u32, 10, 40, 20]);
+        let mut names = TooDee::from_vec(2, 4, vec!["c", "C", "a", "A", "d", "D", "b", "B"]);
+        let perm = keys.view((0, 0), (1, 4)).argsort_by_col(0, |a, b| a.cmp(b));
+        names.view_mut((0, 0), (2, 4)).apply_row_permutation(&perm);
+        assert_eq!(names.data(), &["a", "A", "b", "B", "c", "C", "d", "D"]);
+    }
+
+    #[test]
+    fn argsort_by_row() {
+        let mut toodee = TooDee::from_vec(4, 2, vec![
+            2u32, 9, 0, 5,
+            20, 90, 0, 50,
+        ]);
+        let perm = toodee.argsort_by_row(0, |a, b| b.cmp(a));
+        assert_eq!(perm, vec![1, 3, 0, 2]);
+        assert_eq!(toodee.view((1, 0), (4, 2)).argsort_by_row(1, |a, b| a.cmp(b)), vec![1, 2, 0]);
+        toodee.apply_col_permutation(&perm);
+        assert_eq!(toodee.data(), &[9, 5, 2, 0, 90, 50, 20, 0]);
+        let mut view = toodee.view_mut((1, 0), (4, 2));
+        view.apply_col_permutation(&[2, 0, 1]);
+        assert_eq!(toodee[1], [90, 0, 50, 20]);
+    }
 }
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod toodee_tests_stack {
+
+    use crate::*;
+
+    fn new_3_layers() -> GridStack<u32> {
+        let mut stack = GridStack::new();
+        for z in 0..3 {
+            stack.push_layer(TooDee::from_fn(4, 3, |(c, r)| (z * 100 + r * 10 + c) as u32)).unwrap();
+        }
+        stack
+    }
+
+    #[test]
+    fn three_layers() {
+        let stack = new_3_layers();
+        assert_eq!(stack.num_layers(), 3);
+        assert_eq!(stack.size(), (4, 3));
+        assert_eq!(stack.layer(1)[(2, 1)], 112);
+        assert_eq!(stack.layers().len(), 3);
+        assert_eq!(stack.into_layers()[2][(3, 2)], 223);
+    }
+
+    #[test]
+    fn cell() {
+        let mut stack = new_3_layers();
+        assert_eq!(stack.cell(0, 0, 0), Some(&0));
+        assert_eq!(stack.cell(3, 2, 2), Some(&223));
+        assert_eq!(stack.cell(4, 0, 0), None);
+        assert_eq!(stack.cell(0, 3, 0), None);
+        assert_eq!(stack.cell(0, 0, 3), None);
+        *stack.cell_mut(1, 2, 1).unwrap() = 7;
+        assert_eq!(stack.layer(1)[(1, 2)], 7);
+        assert!(stack.cell_mut(0, 0, 5).is_none());
+        stack.layer_mut(0).fill(9);
+        assert!(stack.layer(0).cells().all(|&v| v == 9));
+    }
+
+    #[test]
+    fn push_layer_mismatch() {
+        let mut stack = new_3_layers();
+        let result = stack.push_layer(TooDee::init(3, 4, 0u32));
+        assert_eq!(result, Err(TooDeeError::DimensionMismatch { expected: (4, 3), actual: (3, 4) }));
+        assert_eq!(stack.num_layers(), 3);
+        assert!(stack.push_layer(TooDee::default()).is_err());
+    }
+
+    #[test]
+    fn from_layers() {
+        let layers = vec![TooDee::init(2, 2, 0u8), TooDee::init(2, 2, 1u8)];
+        assert_eq!(GridStack::from_layers(layers).unwrap().num_layers(), 2);
+        let layers = vec![TooDee::init(2, 2, 0u8), TooDee::init(2, 2, 1u8), TooDee::init(2, 1, 2u8)];
+        assert_eq!(GridStack::from_layers(layers), Err(TooDeeError::DimensionMismatch { expected: (2, 2), actual: (2, 1) }));
+        let empty = GridStack::<u8>::from_layers(vec![]).unwrap();
+        assert_eq!(empty, GridStack::default());
+        assert_eq!(empty.size(), (0, 0));
+    }
+
+    #[test]
+    fn map_layers() {
+        let stack = new_3_layers();
+        let mapped = stack.map_layers(|l| l.map(|&v| v % 2 == 0)).unwrap();
+        assert_eq!(mapped.size(), (4, 3));
+        assert_eq!(mapped.cell(1, 1, 2), Some(&false));
+        let mut z = 0;
+        let result = stack.map_layers(|l| {
+            z += 1;
+            if z == 2 { TooDee::init(1, 1, 0u32) } else { l.clone() }
+        });
+        assert_eq!(result, Err(TooDeeError::DimensionMismatch { expected: (4, 3), actual: (1, 1) }));
+    }
+}
@@ -100,4 +100,140 @@ mod toodee_tests_translate {
 //        println!("{:?}", toodee);
     }
 
+    #[test]
+    fn reverse_rows_in_place_chained() {
+        let mut toodee = new_10_by_10();
+        toodee.reverse_rows_in_place().flip_rows();
+        let mut expected = new_10_by_10();
+        expected.flip_rows();
+        expected.flip_cols();
+        assert_eq!(toodee, expected);
+        assert_eq!(toodee[0][0], 99);
+        assert_eq!(toodee[9][9], 0);
+    }
+
+    #[test]
+    fn reverse_order_of_rows_chained() {
+        let mut toodee = new_10_by_10();
+        toodee.view_mut((0, 0), (10, 5)).reverse_order_of_rows().reverse_order_of_rows().reverse_rows_in_place();
+        assert_eq!(toodee[0][0], 9);
+        assert_eq!(toodee[4][9], 40);
+        assert_eq!(toodee[5][0], 50);
+    }
+
+    #[test]
+    fn try_translate_with_wrap() {
+        let mut toodee = new_10_by_10();
+        assert_eq!(toodee.try_translate_with_wrap((3, 10)), Ok(()));
+        assert_eq!(toodee[0][0], 3);
+        assert_eq!(toodee[0][9], 2);
+    }
+
+    #[test]
+    fn try_translate_with_wrap_out_of_range() {
+        let mut toodee = new_10_by_10();
+        assert_eq!(toodee.try_translate_with_wrap((11, 2)), Err((11, 2)));
+        assert_eq!(toodee.try_translate_with_wrap((2, 11)), Err((2, 11)));
+        assert_eq!(toodee, new_10_by_10());
+    }
+
+    #[test]
+    fn translate_with_fill() {
+        let mut toodee = new_10_by_10();
+        toodee.translate_with_fill((3, 4), &999);
+        let mut wrapped = new_10_by_10();
+        wrapped.translate_with_wrap((3, 4));
+        for r in 0..10 {
+            for c in 0..10 {
+                if c < 7 && r < 6 {
+                    assert_eq!(toodee[(c, r)], wrapped[(c, r)]);
+                    assert_eq!(toodee[(c, r)], ((r + 4) * 10 + c + 3) as u32);
+                } else {
+                    assert_eq!(toodee[(c, r)], 999);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn translate_with_fill_col_or_row_only() {
+        let mut toodee = new_10_by_10();
+        toodee.translate_with_fill((2, 0), &999);
+        assert_eq!(toodee[0][..], [2, 3, 4, 5, 6, 7, 8, 9, 999, 999]);
+        assert_eq!(toodee[9][0], 92);
+        let mut toodee = new_10_by_10();
+        toodee.translate_with_fill((0, 8), &999);
+        assert_eq!(toodee[0][0], 80);
+        assert_eq!(toodee[1][9], 99);
+        assert!(toodee.rows().skip(2).all(|r| r.iter().all(|&v| v == 999)));
+    }
+
+    #[test]
+    fn translate_with_fill_edges() {
+        let mut toodee = new_10_by_10();
+        toodee.translate_with_fill((0, 0), &999);
+        assert_eq!(toodee, new_10_by_10());
+        toodee.translate_with_fill((10, 3), &999);
+        assert!(toodee.cells().all(|&v| v == 999));
+        let mut toodee = new_10_by_10();
+        toodee.view_mut((1, 1), (4, 4)).translate_with_fill((1, 2), &0);
+        assert_eq!(&toodee[1][..5], &[10, 32, 33, 0, 14]);
+        assert_eq!(&toodee[2][..5], &[20, 0, 0, 0, 24]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn translate_with_fill_out_of_range() {
+        let mut toodee = new_10_by_10();
+        toodee.translate_with_fill((11, 0), &0);
+    }
+
+    #[test]
+    fn slide_with_fill() {
+        let mut toodee = new_10_by_10();
+        toodee.slide_with_fill(2, 3, &999);
+        for r in 0..10 {
+            for c in 0..10 {
+                let expected = if c < 2 || r < 3 { 999 } else { (r - 3) * 10 + c - 2 };
+                assert_eq!(toodee[(c, r)], expected as u32);
+            }
+        }
+    }
+
+    #[test]
+    fn slide_with_fill_negative() {
+        let mut toodee = new_10_by_10();
+        toodee.slide_with_fill(-4, -1, &999);
+        for r in 0..10 {
+            for c in 0..10 {
+                let expected = if c >= 6 || r >= 9 { 999 } else { (r + 1) * 10 + c + 4 };
+                assert_eq!(toodee[(c, r)], expected as u32);
+            }
+        }
+    }
+
+    #[test]
+    fn slide_with_fill_view() {
+        let mut toodee = new_10_by_10();
+        toodee.view_mut((2, 2), (5, 4)).slide_with_fill(-1, 1, &0);
+        assert_eq!(&toodee[2][1..6], &[21, 0, 0, 0, 25]);
+        assert_eq!(&toodee[3][1..6], &[31, 23, 24, 0, 35]);
+        assert_eq!(toodee[4][2], 42);
+    }
+
+    #[test]
+    fn slide_with_fill_zero_and_beyond() {
+        let mut toodee = new_10_by_10();
+        toodee.slide_with_fill(0, 0, &999);
+        assert_eq!(toodee, new_10_by_10());
+        toodee.slide_with_fill(10, 0, &999);
+        assert!(toodee.cells().all(|&v| v == 999));
+        let mut toodee = new_10_by_10();
+        toodee.slide_with_fill(0, isize::MIN, &7);
+        assert!(toodee.cells().all(|&v| v == 7));
+        let mut empty = TooDee::<u32>::default();
+        empty.slide_with_fill(1, 1, &0);
+        assert!(empty.is_empty());
+    }
+
 }
@@ -2,24 +2,12 @@
 mod toodee_tests_view {
 
     extern crate alloc;
-    use alloc::boxed::Box;
     use alloc::vec;
     use alloc::vec::Vec;
+    use core::ops::Bound;
 
     use crate::*;
 
-    struct IteratorWithWrongLength();
-
-    impl Iterator for IteratorWithWrongLength {
-        type Item = Box<u8>;
-
-        fn next(&mut self) -> Option<Self::Item> { None }
-    }
-
-    impl ExactSizeIterator for IteratorWithWrongLength {
-        fn len(&self) -> usize { 1 }
-    }
-
     #[test]
     fn new_view_direct() {
         let v = vec![1u32; 32];
@@ -40,6 +28,65 @@ mod toodee_tests_view {
         assert_eq!(view.cells().sum::<u32>(), 32);
     }
 
+    #[test]
+    fn view_with_stride_padded() {
+        let data : Vec<u32> = (0..24).collect();
+        let view = view_with_stride(3, 4, 7, &data).unwrap();
+        assert_eq!(view.size(), (3, 4));
+        assert_eq!(view[0], [0, 1, 2]);
+        assert_eq!(view[3], [21, 22, 23]);
+        assert_eq!(view.col(1).copied().collect::<Vec<_>>(), vec![1, 8, 15, 22]);
+        assert_eq!(view.cells().count(), 12);
+        let packed : TooDee<u32> = view.into();
+        assert_eq!(packed.data(), &[0, 1, 2, 7, 8, 9, 14, 15, 16, 21, 22, 23]);
+    }
+
+    #[test]
+    fn view_with_stride_invalid() {
+        let data : Vec<u32> = (0..24).collect();
+        assert!(view_with_stride(3, 4, 7, &data[..23]).is_none());
+        assert!(view_with_stride(8, 2, 7, &data).is_none());
+        assert!(view_with_stride(0, 2, 7, &data).is_none());
+        assert!(view_with_stride(2, usize::MAX, usize::MAX, &data).is_none());
+        assert!(view_with_stride(0, 0, 0, &data[..0]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn col_len() {
+        let mut toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        let view = toodee.view((1, 1), (3, 3));
+        let mut col = view.col(1);
+        assert_eq!(col.col_len(), 2);
+        assert_eq!(col.col_len(), col.len());
+        col.next();
+        assert_eq!(col.col_len(), 1);
+        let mut view = toodee.view_mut((0, 0), (2, 3));
+        let mut col = view.col_mut(0);
+        assert_eq!(col.col_len(), 3);
+        col.nth(1);
+        assert_eq!(col.col_len(), 1);
+    }
+
+    #[test]
+    fn zero_row_view_has_no_cols() {
+        let mut toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        // empty views always have zero dimensions, so there are no columns to fetch
+        let view = toodee.view((1, 2), (3, 2));
+        assert_eq!(view.size(), (0, 0));
+        assert_eq!(view.cols().len(), 0);
+        let mut view = toodee.view_mut((1, 2), (3, 2));
+        assert_eq!(view.size(), (0, 0));
+        assert_eq!(view.cols_mut().len(), 0);
+        assert!(view_with_stride(2, 0, 4, toodee.data()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn zero_row_view_col() {
+        let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+        toodee.view((1, 2), (3, 2)).col(0);
+    }
+
     #[test]
     fn view_from_into_toodee() {
         let toodee = TooDee::from_vec(10, 10, (0u32..100).collect());
@@ -151,14 +198,14 @@ mod toodee_tests_view {
     }
 
     #[test]
-    #[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+    #[should_panic(expected = "num_cols * num_rows overflows usize")]
     fn toodee_view_new_overflow() {
         // const orig: TooDee<u32> = TooDee::new(1, 1);
         TooDeeView::<u32>::new(usize::MAX, usize::MAX, &[0u32] );
     }
 
     #[test]
-    #[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+    #[should_panic(expected = "num_cols * num_rows overflows usize")]
     fn toodee_view_mut_new_overflow() {
         // const orig: TooDee<u32> = TooDee::new(1, 1);
         TooDeeViewMut::<u32>::new(usize::MAX, usize::MAX, &mut [0u32]);
@@ -168,15 +215,15 @@ mod toodee_tests_view {
     fn swap() {
         let mut toodee = TooDee::from_vec(5, 5, (0u32..25).collect());
         let mut view = toodee.view_mut((1, 1), (4, 4));
-        assert_eq!(&view.cells().map(|arg| { arg.clone() }).collect::<Vec<u32>>(), &[6, 7, 8, 11, 12, 13, 16, 17, 18]);
+        assert_eq!(&view.cells().copied().collect::<Vec<u32>>(), &[6, 7, 8, 11, 12, 13, 16, 17, 18]);
         view.swap((0,0),(2, 2));
-        assert_eq!(&view.cells().map(|arg| { arg.clone() }).collect::<Vec<u32>>(), &[18, 7, 8, 11, 12, 13, 16, 17, 6]);
+        assert_eq!(&view.cells().copied().collect::<Vec<u32>>(), &[18, 7, 8, 11, 12, 13, 16, 17, 6]);
         view.swap((2,2),(0, 0));
-        assert_eq!(&view.cells().map(|arg| { arg.clone() }).collect::<Vec<u32>>(), &[6, 7, 8, 11, 12, 13, 16, 17, 18]);
+        assert_eq!(&view.cells().copied().collect::<Vec<u32>>(), &[6, 7, 8, 11, 12, 13, 16, 17, 18]);
         view.swap((0,2),(1, 1));
-        assert_eq!(&view.cells().map(|arg| { arg.clone() }).collect::<Vec<u32>>(), &[6, 7, 8, 11, 16, 13, 12, 17, 18]);
+        assert_eq!(&view.cells().copied().collect::<Vec<u32>>(), &[6, 7, 8, 11, 16, 13, 12, 17, 18]);
         view.swap((1,1),(1, 1));
-        assert_eq!(&view.cells().map(|arg| { arg.clone() }).collect::<Vec<u32>>(), &[6, 7, 8, 11, 16, 13, 12, 17, 18]);
+        assert_eq!(&view.cells().copied().collect::<Vec<u32>>(), &[6, 7, 8, 11, 16, 13, 12, 17, 18]);
     }
 
     #[test]
@@ -195,4 +242,417 @@ mod toodee_tests_view {
         view.swap((3,0), (1,1));
     }
 
+    #[test]
+    fn split_at_col_mut() {
+        let mut toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        let mut view = toodee.view_mut((1, 1), (5, 4));
+        let (mut left, mut right) = view.split_at_col_mut(1);
+        assert_eq!(left.size(), (1, 3));
+        assert_eq!(right.size(), (3, 3));
+        left.fill(100);
+        right.cells_mut().for_each(|v| *v += 1000);
+        assert_eq!(toodee.data(), &[
+            0, 1, 2, 3, 4,
+            5, 100, 1007, 1008, 1009,
+            10, 100, 1012, 1013, 1014,
+            15, 100, 1017, 1018, 1019,
+        ]);
+    }
+
+    #[test]
+    fn split_at_col_mut_no_aliasing() {
+        let mut toodee = TooDee::init(6, 3, 0u32);
+        let mut view = toodee.view_mut((0, 0), (6, 3));
+        let (mut left, mut right) = view.split_at_col_mut(3);
+        // hold mutable row iterators over both halves at the same time
+        for (l, r) in left.rows_mut().zip(right.rows_mut()) {
+            l.fill(1);
+            r.fill(2);
+        }
+        let mut col = right.col_mut(0);
+        col[1] = 3;
+        for r in left.rows() {
+            assert_eq!(r, &[1, 1, 1]);
+        }
+        assert_eq!(toodee.data(), &[
+            1, 1, 1, 2, 2, 2,
+            1, 1, 1, 3, 2, 2,
+            1, 1, 1, 2, 2, 2,
+        ]);
+    }
+
+    #[test]
+    fn split_at_col_mut_threads() {
+        let mut toodee = TooDee::init(8, 8, 0u32);
+        let mut view = toodee.view_mut((0, 0), (8, 8));
+        let (mut left, mut right) = view.split_at_col_mut(5);
+        std::thread::scope(|s| {
+            s.spawn(|| left.fill(1));
+            s.spawn(|| right.fill(2));
+        });
+        for r in toodee.rows() {
+            assert_eq!(r, &[1, 1, 1, 1, 1, 2, 2, 2]);
+        }
+    }
+
+    #[test]
+    fn split_at_col_mut_edges() {
+        let mut toodee = TooDee::init(4, 3, 0u32);
+        let mut view = toodee.view_mut((0, 0), (4, 3));
+        let (left, right) = view.split_at_col_mut(0);
+        assert_eq!(left.size(), (0, 0));
+        assert_eq!(right.size(), (4, 3));
+        let (left, right) = view.split_at_col_mut(4);
+        assert_eq!(left.size(), (4, 3));
+        assert_eq!(right.size(), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn split_at_col_mut_out_of_bounds() {
+        let mut toodee = TooDee::init(4, 3, 0u32);
+        let mut view = toodee.view_mut((0, 0), (4, 3));
+        view.split_at_col_mut(5);
+    }
+
+    #[test]
+    fn split_at_col_mut_toodee() {
+        let mut toodee = TooDee::from_vec(3, 2, (0u32..6).collect());
+        let (mut left, right) = toodee.split_at_col_mut(2);
+        assert_eq!(right.parent_coord((0, 1)), (2, 1));
+        left.fill(9);
+        assert_eq!(toodee.data(), &[9, 9, 2, 9, 9, 5]);
+    }
+
+    #[test]
+    fn split_at_row_mut() {
+        let mut toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        let mut view = toodee.view_mut((1, 1), (4, 4));
+        let (mut top, mut bottom) = view.split_at_row_mut(1);
+        assert_eq!(top.size(), (3, 1));
+        assert_eq!(bottom.size(), (3, 2));
+        assert_eq!(bottom.parent_coord((0, 0)), (1, 2));
+        top.fill(100);
+        bottom.cells_mut().for_each(|v| *v += 1000);
+        assert_eq!(toodee.data(), &[
+            0, 1, 2, 3, 4,
+            5, 100, 100, 100, 9,
+            10, 1011, 1012, 1013, 14,
+            15, 1016, 1017, 1018, 19,
+        ]);
+    }
+
+    #[test]
+    fn split_at_row_mut_threads() {
+        let mut toodee = TooDee::init(8, 8, 0u32);
+        let (mut top, mut bottom) = toodee.split_at_row_mut(3);
+        std::thread::scope(|s| {
+            s.spawn(|| top.fill(1));
+            s.spawn(|| bottom.fill(2));
+        });
+        assert!(toodee.rows().take(3).all(|r| r == [1; 8]));
+        assert!(toodee.rows().skip(3).all(|r| r == [2; 8]));
+    }
+
+    #[test]
+    fn split_at_row_mut_edges() {
+        let mut toodee = TooDee::init(4, 3, 0u32);
+        // a view whose last row ends before the end of the buffer
+        let mut view = toodee.view_mut((0, 0), (2, 3));
+        let (top, bottom) = view.split_at_row_mut(0);
+        assert_eq!(top.size(), (0, 0));
+        assert_eq!(bottom.size(), (2, 3));
+        let (top, bottom) = view.split_at_row_mut(3);
+        assert_eq!(top.size(), (2, 3));
+        assert_eq!(bottom.size(), (0, 0));
+        assert_eq!(bottom.cells().count(), 0);
+        let mut empty = TooDee::<u32>::default();
+        let (top, bottom) = empty.split_at_row_mut(0);
+        assert!(top.is_empty() && bottom.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn split_at_row_mut_out_of_bounds() {
+        let mut toodee = TooDee::init(4, 3, 0u32);
+        toodee.split_at_row_mut(4);
+    }
+
+    #[test]
+    fn view_eq_ignores_stride() {
+        let toodee = TooDee::from_vec(4, 2, vec![1u32, 2, 9, 9, 3, 4, 9, 9]);
+        let packed = vec![1u32, 2, 3, 4];
+        assert_eq!(toodee.view((0, 0), (2, 2)), TooDeeView::new(2, 2, &packed));
+        assert_ne!(toodee.view((0, 0), (2, 1)), TooDeeView::new(2, 2, &packed));
+    }
+
+    #[test]
+    fn two_views_mut_disjoint() {
+        let mut toodee = TooDee::init(6, 4, 0u32);
+        let (mut a, mut b) = toodee.two_views_mut(((0, 0), (3, 4)), ((3, 1), (6, 3))).unwrap();
+        assert_eq!(a.size(), (3, 4));
+        assert_eq!(b.size(), (3, 2));
+        for (ra, rb) in a.rows_mut().zip(b.rows_mut()) {
+            ra.fill(1);
+            rb.fill(2);
+        }
+        assert_eq!(toodee.data(), &[
+            1, 1, 1, 0, 0, 0,
+            1, 1, 1, 2, 2, 2,
+            0, 0, 0, 2, 2, 2,
+            0, 0, 0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn two_views_mut_overlapping() {
+        let mut toodee = TooDee::init(6, 4, 0u32);
+        assert!(toodee.two_views_mut(((0, 0), (4, 4)), ((3, 3), (6, 4))).is_none());
+        assert!(toodee.two_views_mut(((1, 1), (5, 3)), ((0, 0), (6, 4))).is_none());
+        assert!(toodee.two_views_mut(((1, 1), (2, 2)), ((1, 1), (2, 2))).is_none());
+    }
+
+    #[test]
+    fn two_views_mut_of_view() {
+        let mut toodee = TooDee::init(6, 4, 0u32);
+        let mut view = toodee.view_mut((1, 1), (5, 4));
+        let (mut a, mut b) = view.two_views_mut(((0, 0), (2, 1)), ((2, 0), (4, 1))).unwrap();
+        a.fill(1);
+        b.fill(2);
+        assert_eq!(toodee[1], [0, 1, 1, 2, 2, 0]);
+    }
+
+    #[test]
+    fn two_views_mut_empty() {
+        let mut toodee = TooDee::init(6, 4, 0u32);
+        let (a, b) = toodee.two_views_mut(((2, 2), (2, 2)), ((0, 0), (6, 4))).unwrap();
+        assert!(a.is_empty());
+        assert_eq!(b.size(), (6, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn two_views_mut_out_of_bounds() {
+        let mut toodee = TooDee::init(6, 4, 0u32);
+        let _ = toodee.two_views_mut(((0, 0), (1, 1)), ((5, 0), (7, 1)));
+    }
+
+    #[test]
+    fn to_packed() {
+        let toodee = TooDee::from_vec(7, 6, (0u32..42).collect());
+        let view = toodee.view((2, 1), (6, 5));
+        let packed = view.to_packed();
+        assert_eq!(packed, TooDee::from(view));
+        assert_eq!(packed.size(), (4, 4));
+        assert_eq!(packed[0], [9, 10, 11, 12]);
+        assert_eq!(packed[3], [30, 31, 32, 33]);
+    }
+
+    #[test]
+    fn to_owned_transposed() {
+        let toodee = TooDee::from_vec(7, 6, (0u32..42).collect());
+        let view = toodee.view((2, 1), (6, 4));
+        let transposed = view.to_owned_transposed();
+        assert_eq!(transposed.size(), (3, 4));
+        assert_eq!(transposed[0], [9, 16, 23]);
+        assert_eq!(transposed[3], [12, 19, 26]);
+        let mut expected = view.to_packed();
+        expected.transpose_in_place_rect();
+        assert_eq!(transposed, expected);
+        assert!(toodee.view((2, 1), (2, 5)).to_owned_transposed().is_empty());
+    }
+
+    #[test]
+    fn to_owned_transposed_strided() {
+        let mut toodee = TooDee::from_fn(9, 6, |(c, r)| (r * 10 + c) as u32);
+        let outer = toodee.view((1, 1), (8, 6));
+        let inner = outer.view((2, 1), (5, 4));
+        let transposed = inner.to_owned_transposed();
+        assert_eq!(transposed.size(), (3, 3));
+        assert_eq!(transposed.data(), &[23, 33, 43, 24, 34, 44, 25, 35, 45]);
+        let view_mut = toodee.view_mut((7, 0), (9, 6));
+        assert_eq!(view_mut.to_owned_transposed().data(), &[7, 17, 27, 37, 47, 57, 8, 18, 28, 38, 48, 58]);
+        let data : Vec<u32> = (0..11).collect();
+        let padded = view_with_stride(3, 3, 4, &data).unwrap();
+        assert_eq!(padded.to_owned_transposed().data(), &[0, 4, 8, 1, 5, 9, 2, 6, 10]);
+    }
+
+    #[test]
+    fn to_owned_transposed_multiple_blocks() {
+        let toodee = TooDee::from_fn(80, 75, |(c, r)| (r * 80 + c) as u32);
+        let view = toodee.view((3, 2), (73, 69));
+        let transposed = view.to_owned_transposed();
+        assert_eq!(transposed.size(), (67, 70));
+        for (c, r) in (0..70).flat_map(|c| (0..67).map(move |r| (c, r))) {
+            assert_eq!(transposed[(r, c)], view[(c, r)]);
+        }
+    }
+
+    #[test]
+    fn to_packed_empty() {
+        let toodee = TooDee::from_vec(7, 6, (0u32..42).collect());
+        let packed = toodee.view((2, 1), (2, 5)).to_packed();
+        assert!(packed.is_empty());
+    }
+
+    #[test]
+    fn parent_coord() {
+        let mut toodee = TooDee::from_vec(8, 8, (0u32..64).collect());
+        let view = toodee.view((2, 3), (7, 8));
+        assert_eq!(view.parent_coord((0, 0)), (2, 3));
+        assert_eq!(view.parent_coord((4, 2)), (6, 5));
+        let inner = view.view((1, 1), (3, 3));
+        assert_eq!(inner.parent_coord((1, 1)), (4, 5));
+        assert_eq!(inner[(1, 1)], toodee[inner.parent_coord((1, 1))]);
+        let mut view_mut = toodee.view_mut((2, 3), (7, 8));
+        assert_eq!(view_mut.parent_coord((4, 2)), (6, 5));
+        let (_, right) = view_mut.split_at_col_mut(2);
+        assert_eq!(right.parent_coord((0, 0)), (4, 3));
+    }
+
+    #[test]
+    fn indexed_cells_parent() {
+        let toodee = TooDee::from_vec(8, 8, (0u32..64).collect());
+        let view = toodee.view((2, 3), (5, 5));
+        let cells : Vec<_> = view.indexed_cells_parent().collect();
+        assert_eq!(cells.len(), 6);
+        for (coord, v) in cells {
+            assert_eq!(toodee[coord], *v);
+        }
+        let last = view.indexed_cells_parent().next_back();
+        assert_eq!(last, Some(((4, 4), &36)));
+    }
+
+    #[test]
+    fn indexed_cells_parent_mut() {
+        let mut toodee = TooDee::init(4, 4, 0u32);
+        let mut view = toodee.view_mut((1, 2), (3, 4));
+        view.fill(1);
+        let coords : Vec<_> = view.indexed_cells_parent().map(|(c, _)| c).collect();
+        assert_eq!(coords, vec![(1, 2), (2, 2), (1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn slice_rows() {
+        let toodee = TooDee::from_vec(3, 6, (0u32..18).collect());
+        let view = toodee.slice_rows(2..5);
+        assert_eq!(view.size(), (3, 3));
+        assert_eq!(view[0], [6, 7, 8]);
+        assert_eq!(view[2], [12, 13, 14]);
+        let view = toodee.slice_rows(..3);
+        assert_eq!(view.size(), (3, 3));
+        assert_eq!(view[2], [6, 7, 8]);
+        let view = toodee.slice_rows(2..);
+        assert_eq!(view.size(), (3, 4));
+        assert_eq!(view[3], [15, 16, 17]);
+        let view = toodee.slice_rows(..);
+        assert_eq!(view, toodee.view((0, 0), (3, 6)));
+        assert!(toodee.slice_rows(4..4).is_empty());
+        let tail = toodee.slice_rows(1..);
+        let nested = tail.slice_rows(1..=2);
+        assert_eq!(nested[0], [6, 7, 8]);
+        assert_eq!(nested.num_rows(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end is out of bounds")]
+    fn slice_rows_out_of_bounds() {
+        let toodee = TooDee::from_vec(3, 6, (0u32..18).collect());
+        toodee.slice_rows(2..7);
+    }
+
+    #[test]
+    #[should_panic(expected = "start bound overflow")]
+    fn slice_rows_excluded_start_overflow() {
+        let toodee = TooDee::from_vec(3, 6, (0u32..18).collect());
+        toodee.slice_rows((Bound::Excluded(usize::MAX), Bound::Unbounded));
+    }
+
+    #[test]
+    #[should_panic(expected = "range start is greater than range end")]
+    fn slice_rows_start_after_end() {
+        let toodee = TooDee::from_vec(3, 6, (0u32..18).collect());
+        toodee.slice_rows((Bound::Excluded(3), Bound::Excluded(3)));
+    }
+
+    #[test]
+    fn rotate_90_cw_into_view() {
+        let toodee = TooDee::from_vec(2, 4, (1u32..=8).collect());
+        let mut dst = TooDee::init(6, 4, 0u32);
+        toodee.rotate_90_cw_into(&mut dst.view_mut((1, 1), (5, 3)));
+        assert_eq!(dst.data(), &[
+            0, 0, 0, 0, 0, 0,
+            0, 7, 5, 3, 1, 0,
+            0, 8, 6, 4, 2, 0,
+            0, 0, 0, 0, 0, 0,
+        ]);
+        let mut rotated = TooDee::init(4, 2, 0u32);
+        toodee.view((0, 0), (2, 4)).rotate_90_cw_into(&mut rotated);
+        assert_eq!(rotated, dst.view((1, 1), (5, 3)).into());
+    }
+
+    #[test]
+    fn transpose_into_view() {
+        let toodee = TooDee::from_vec(2, 3, (1u32..=6).collect());
+        let mut dst = TooDee::init(5, 4, 0u32);
+        toodee.transpose_into_view(&mut dst.view_mut((2, 1), (5, 3)));
+        assert_eq!(dst.data(), &[
+            0, 0, 0, 0, 0,
+            0, 0, 1, 3, 5,
+            0, 0, 2, 4, 6,
+            0, 0, 0, 0, 0,
+        ]);
+        let mut transposed = toodee.clone();
+        transposed.transpose_in_place_rect();
+        assert_eq!(transposed, dst.view((2, 1), (5, 3)).into());
+    }
+
+    #[test]
+    #[should_panic(expected = "left == right")]
+    fn transpose_into_view_bad_size() {
+        let toodee = TooDee::from_vec(2, 3, (1u32..=6).collect());
+        let mut dst = TooDee::init(5, 4, 0u32);
+        toodee.transpose_into_view(&mut dst.view_mut((0, 0), (2, 3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "left == right")]
+    fn rotate_90_cw_into_bad_size() {
+        let toodee = TooDee::from_vec(2, 4, (1u32..=8).collect());
+        let mut dst = TooDee::init(2, 4, 0u32);
+        toodee.rotate_90_cw_into(&mut dst);
+    }
+
+    #[test]
+    fn map_windows_local_max() {
+        let toodee = TooDee::from_vec(5, 4, vec![
+            1, 2, 3, 4, 5,
+            6, 9, 0, 0, 7,
+            0, 0, 0, 8, 0,
+            3, 0, 0, 0, 2,
+        ]);
+        let maxima = toodee.map_windows(1, |w| *w.cells().max().unwrap());
+        assert_eq!(maxima.size(), (3, 2));
+        assert_eq!(maxima.data(), &[9, 9, 8, 9, 9, 8]);
+        let view = toodee.view((1, 0), (5, 3));
+        let centres = view.map_windows(1, |w| w[(1, 1)]);
+        assert_eq!(centres.data(), &[0, 0]);
+        assert_eq!(toodee.map_windows(0, |w| w[(0, 0)]), toodee);
+    }
+
+    #[test]
+    fn map_windows_too_small() {
+        let toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        let result = toodee.map_windows(2, |w| w.num_cols());
+        assert!(result.is_empty());
+        assert_eq!(result.size(), (0, 0));
+    }
+
+    #[test]
+    fn map_windows_huge_radius() {
+        let toodee = TooDee::from_vec(5, 4, (0u32..20).collect());
+        // the window size overflows rather than wrapping to a single cell
+        assert!(toodee.map_windows(1 << (usize::BITS - 1), |w| w.num_cols()).is_empty());
+        assert!(toodee.map_windows(usize::MAX, |w| w.num_cols()).is_empty());
+    }
 }
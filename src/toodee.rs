@@ -1,12 +1,10 @@
 use core::fmt;
 use core::fmt::{ Formatter, Debug };
-use core::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut, Range};
 use core::iter::IntoIterator;
 use core::ptr::{self, NonNull};
 use core::mem;
-use core::slice;
-
-extern crate alloc;
+use core::str::FromStr;
 
 use alloc::boxed::Box;
 use alloc::vec;
@@ -17,6 +15,8 @@ use alloc::vec::IntoIter;
 use crate::iter::*;
 use crate::view::*;
 use crate::ops::*;
+use crate::error::*;
+use crate::builder::*;
 
 /// DrainRow type alias for future-proofing.
 pub type DrainRow<'a, T> = Drain<'a, T>;
@@ -28,7 +28,6 @@ pub type IntoIterTooDee<T> = IntoIter<T>;
 /// 
 /// Empty arrays will always have dimensions of zero.
 #[derive(Clone, Hash, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TooDee<T> {
     data: Vec<T>,
     num_rows: usize,
@@ -182,10 +181,8 @@ impl<T> TooDeeOps<T> for TooDee<T> {
     /// assert_eq!(r0.len(), 10);
     /// ```
     fn rows(&self) -> Rows<'_, T> {
-        Rows {
-            v : &self.data,
-            cols : self.num_cols,
-            skip_cols : 0,
+        unsafe {
+            Rows::new(self.data.as_ptr(), self.num_rows, self.num_cols, self.num_cols)
         }
     }
     
@@ -200,10 +197,7 @@ impl<T> TooDeeOps<T> for TooDee<T> {
     fn col(&self, col: usize) -> Col<'_, T> {
         assert!(col < self.num_cols);
         unsafe {
-            Col {
-                v : self.data.get_unchecked(col..self.data.len() - self.num_cols + col + 1),
-                skip : self.num_cols - 1,
-            }
+            Col::new(self.data.as_ptr().add(col), self.num_rows, self.num_cols)
         }
     }
 
@@ -263,10 +257,8 @@ impl<T> TooDeeOpsMut<T> for TooDee<T> {
     /// assert_eq!(r0.len(), 10);
     /// ```
     fn rows_mut(&mut self) -> RowsMut<'_, T> {
-        RowsMut {
-            v : &mut self.data,
-            cols : self.num_cols,
-            skip_cols : 0,
+        unsafe {
+            RowsMut::new(self.data.as_mut_ptr(), self.num_rows, self.num_cols, self.num_cols)
         }
     }
     
@@ -280,12 +272,8 @@ impl<T> TooDeeOpsMut<T> for TooDee<T> {
     /// ```
     fn col_mut(&mut self, col: usize) -> ColMut<'_, T> {
         assert!(col < self.num_cols);
-        let dlen = self.data.len();
         unsafe {
-            ColMut {
-                v : self.data.get_unchecked_mut(col..dlen - self.num_cols + col + 1),
-                skip : self.num_cols - 1,
-            }
+            ColMut::new(self.data.as_mut_ptr().add(col), self.num_rows, self.num_cols)
         }
     }
     
@@ -419,7 +407,8 @@ impl<T> TooDee<T> {
     pub fn new(num_cols: usize, num_rows: usize) -> TooDee<T>
     where T: Default {
         let mut data = Vec::new();
-        data.resize_with(num_cols.checked_mul(num_rows).unwrap(), T::default);
+        let len = num_cols.checked_mul(num_rows).unwrap_or_else(|| panic!("{}", TooDeeError::DimensionOverflow));
+        data.resize_with(len, T::default);
         TooDee { data, num_cols, num_rows }
     }
 
@@ -444,15 +433,84 @@ impl<T> TooDee<T> {
     /// ```
     pub fn init(num_cols: usize, num_rows: usize, init_value: T) -> TooDee<T>
     where T: Clone {
-        if num_cols == 0 || num_rows == 0 {
-            assert_eq!(num_rows, num_cols);
-        }
-        let len = num_rows.checked_mul(num_cols).unwrap();
-        let v = vec![init_value; len];
-        TooDee {
-            data : v,
+        TooDee::try_init(num_cols, num_rows, init_value).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a new `TooDee` array of the specified dimensions, and fill it with
+    /// an initial value, as per `init()`, but return an error instead of panicking.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `TooDeeError::InconsistentZeroDimension` if one of the dimensions is zero
+    /// but the other is non-zero.
+    ///
+    /// Returns `TooDeeError::DimensionOverflow` if `num_rows * num_cols` overflows.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeError};
+    /// let toodee = TooDee::try_init(10, 5, 42u32).unwrap();
+    /// assert_eq!(toodee.size(), (10, 5));
+    /// assert_eq!(TooDee::try_init(0, 5, 42u32), Err(TooDeeError::InconsistentZeroDimension));
+    /// ```
+    pub fn try_init(num_cols: usize, num_rows: usize, init_value: T) -> Result<TooDee<T>, TooDeeError>
+    where T: Clone {
+        let len = checked_size(num_cols, num_rows)?;
+        Ok(TooDee {
+            data : vec![init_value; len],
             num_cols,
             num_rows,
+        })
+    }
+
+    /// Create a new `TooDee` array of the specified dimensions, where each cell is
+    /// initialised by calling `f((col, row))`. Cells are visited in row-major order.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if one of the dimensions is zero but the other is non-zero. This
+    /// is to enforce the rule that empty arrays have no dimensions.
+    ///
+    /// Panics if `num_rows * num_cols` overflows.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_fn(3, 2, |(col, row)| (col + row) as u8);
+    /// assert_eq!(toodee.data(), &[0, 1, 2, 1, 2, 3]);
+    /// ```
+    pub fn from_fn<F>(num_cols: usize, num_rows: usize, mut f: F) -> TooDee<T>
+    where F: FnMut(Coordinate) -> T {
+        let len = checked_size(num_cols, num_rows).unwrap_or_else(|e| panic!("{}", e));
+        let mut data = Vec::with_capacity(len);
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                data.push(f((col, row)));
+            }
+        }
+        TooDee { data, num_cols, num_rows }
+    }
+
+    /// Creates a new `TooDee` array with the same dimensions, where each cell is the
+    /// result of applying `f` to the corresponding cell. Cells are visited in row-major order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee : TooDee<u8> = TooDee::from_vec(2, 2, vec![0, 51, 102, 255]);
+    /// let scaled = toodee.map(|&v| v as f32 / 255.0);
+    /// assert_eq!(scaled.size(), (2, 2));
+    /// assert_eq!(scaled.data(), &[0.0, 0.2, 0.4, 1.0]);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> TooDee<U>
+    where F: FnMut(&T) -> U {
+        TooDee {
+            data : self.data.iter().map(f).collect(),
+            num_cols : self.num_cols,
+            num_rows : self.num_rows,
         }
     }
     
@@ -552,15 +610,40 @@ impl<T> TooDee<T> {
     /// assert_eq!(toodee[0][0], 42);
     /// ```
     pub fn from_vec(num_cols: usize, num_rows: usize, v: Vec<T>) -> TooDee<T> {
-        if num_cols == 0 || num_rows == 0 {
-            assert_eq!(num_rows, num_cols);
+        TooDee::try_from_vec(num_cols, num_rows, v).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a new `TooDee` array using the provided vector, returning an error rather than
+    /// panicking if the dimensions are invalid, e.g., because they come from untrusted input.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `TooDeeError::InconsistentZeroDimension` if one of the dimensions is zero
+    /// but the other is non-zero.
+    ///
+    /// Returns `TooDeeError::DimensionOverflow` if `num_cols * num_rows` overflows.
+    ///
+    /// Returns `TooDeeError::LengthMismatch` if the vector's length doesn't match the dimensions.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeError};
+    /// let toodee = TooDee::try_from_vec(5, 2, vec![42u32; 10]).unwrap();
+    /// assert_eq!(toodee.size(), (5, 2));
+    /// let result = TooDee::try_from_vec(4, 2, vec![42u32; 10]);
+    /// assert_eq!(result, Err(TooDeeError::LengthMismatch { expected: 8, actual: 10 }));
+    /// ```
+    pub fn try_from_vec(num_cols: usize, num_rows: usize, v: Vec<T>) -> Result<TooDee<T>, TooDeeError> {
+        let len = checked_size(num_cols, num_rows)?;
+        if len != v.len() {
+            return Err(TooDeeError::LengthMismatch { expected: len, actual: v.len() });
         }
-        assert_eq!(num_cols.checked_mul(num_rows).unwrap(), v.len());
-        TooDee {
+        Ok(TooDee {
             data : v,
             num_cols,
             num_rows,
-        }
+        })
     }
     
     /// Create a new `TooDee` array using the provided boxed slice. The slice's length
@@ -580,6 +663,31 @@ impl<T> TooDee<T> {
         TooDee::from_vec(num_cols, num_rows, b.into_vec())
     }
 
+    /// Create a new `TooDee` array using the provided boxed slice, returning the boxed slice
+    /// back to the caller if its length doesn't match the dimensions of the array.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let b = vec![42u32; 10].into_boxed_slice();
+    /// let toodee = TooDee::try_from_box(5, 2, b).unwrap();
+    /// assert_eq!(toodee.size(), (5, 2));
+    /// let b = vec![42u32; 10].into_boxed_slice();
+    /// let b = TooDee::try_from_box(4, 2, b).unwrap_err();
+    /// assert_eq!(b.len(), 10);
+    /// ```
+    pub fn try_from_box(num_cols: usize, num_rows: usize, b: Box<[T]>) -> Result<TooDee<T>, Box<[T]>> {
+        match checked_size(num_cols, num_rows) {
+            Ok(len) if len == b.len() => Ok(TooDee {
+                data : b.into_vec(),
+                num_cols,
+                num_rows,
+            }),
+            _ => Err(b),
+        }
+    }
+
     /// Returns a reference to the raw array data
     /// 
     /// # Examples
@@ -607,6 +715,30 @@ impl<T> TooDee<T> {
     pub fn data_mut(&mut self) -> &mut [T] {
         &mut self.data
     }
+
+    /// Returns the rows as a slice of fixed-size arrays, or `None` if `num_cols` isn't `N`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let rows : &[[u32; 3]] = toodee.as_row_arrays().unwrap();
+    /// assert_eq!(rows, &[[1, 2, 3], [4, 5, 6]]);
+    /// assert!(toodee.as_row_arrays::<2>().is_none());
+    /// ```
+    pub fn as_row_arrays<const N: usize>(&self) -> Option<&[[T; N]]> {
+        if self.num_cols != N {
+            return None;
+        }
+        if N == 0 {
+            // the array is empty, and `as_chunks()` doesn't support zero-length chunks
+            return Some(&[]);
+        }
+        let (rows, remainder) = self.data.as_chunks::<N>();
+        debug_assert!(remainder.is_empty());
+        Some(rows)
+    }
     
     
     /// Clears the array, removing all values and zeroing the number of columns and rows.
@@ -804,15 +936,12 @@ impl<T> TooDee<T> {
 
         let v = &mut self.data;
         let num_cols = self.num_cols;
-        let slice_len = v.len() - num_cols + 1;
+        let num_rows = self.num_rows;
         unsafe {
             // set the vec length to 0 to amplify any leaks
             v.set_len(0);
             DrainCol {
-               iter : Col {
-                   skip : num_cols - 1,
-                   v : slice::from_raw_parts_mut(v.as_mut_ptr().add(index), slice_len),
-               },
+               iter : Col::new(v.as_mut_ptr().add(index), num_rows, num_cols),
                col : index,
                toodee : NonNull::from(self),
             }
@@ -894,11 +1023,388 @@ impl<T> TooDee<T> {
         }
     }
 
+    /// Inserts new `data` into the array at the specified `col`. This is equivalent to `insert_col()`,
+    /// but takes a slice of `Copy` values, so there is no need to guard against a misbehaving iterator.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the data's length doesn't match the length of existing columns (if any).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(2, 2, vec![1, 2, 3, 4]);
+    /// toodee.insert_col_from_slice(1, &[7, 8]);
+    /// assert_eq!(toodee.size(), (3, 2));
+    /// assert_eq!(toodee.data(), &[1, 7, 2, 3, 8, 4]);
+    /// ```
+    pub fn insert_col_from_slice(&mut self, index: usize, data: &[T])
+    where T: Copy {
+        assert!(index <= self.num_cols);
+        if self.num_cols == 0 {
+            self.num_rows = data.len();
+        } else {
+            assert_eq!(self.num_rows, data.len());
+        }
+        if data.is_empty() {
+            return;
+        }
+
+        let old_cols = self.num_cols;
+        let new_cols = old_cols + 1;
+        let old_len = self.data.len();
+        self.reserve(self.num_rows);
+
+        // `T: Copy`, so nothing in here can panic or needs dropping.
+        unsafe {
+            let p = self.data.as_mut_ptr();
+            // Work backwards so that each row is moved before it is overwritten. The cells after the
+            // new column in one row and the cells before it in the next row are moved as a single block.
+            let mut block_end = old_len;
+            for (row, &v) in data.iter().enumerate().rev() {
+                let block_start = row * old_cols + index;
+                ptr::copy(p.add(block_start), p.add(row * new_cols + index + 1), block_end - block_start);
+                ptr::write(p.add(row * new_cols + index), v);
+                block_end = block_start;
+            }
+            // the first row's prefix is already in place
+            self.data.set_len(old_len + self.num_rows);
+        }
+
+        self.num_cols = new_cols;
+    }
+
+    /// Resizes the array to the new dimensions. Cells whose coordinates remain in range are kept
+    /// (anchored at the top-left), cells outside the new bounds are dropped, and any newly exposed
+    /// cells are set to `fill`. At most one reallocation takes place.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if one of the new dimensions is zero but the other is non-zero. This
+    /// is to enforce the rule that empty arrays have no dimensions.
+    ///
+    /// Panics if `new_rows * new_cols` overflows.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// toodee.resize(2, 3, 0);
+    /// assert_eq!(toodee.size(), (2, 3));
+    /// assert_eq!(toodee.data(), &[1, 2, 4, 5, 0, 0]);
+    /// ```
+    pub fn resize(&mut self, new_cols: usize, new_rows: usize, fill: T)
+    where T: Clone {
+        let new_len = checked_size(new_cols, new_rows).unwrap_or_else(|e| panic!("{}", e));
+        let old_cols = self.num_cols;
+        let keep_rows = self.num_rows.min(new_rows);
+        // leave the array empty (rather than inconsistent) if `fill.clone()` panics
+        let mut old = mem::take(&mut self.data);
+        self.num_cols = 0;
+        self.num_rows = 0;
+
+        let data = if old_cols == new_cols || keep_rows == 0 {
+            // rows keep their position, so the cells can be truncated/extended in place
+            old.truncate(keep_rows * new_cols);
+            old.resize(new_len, fill);
+            old
+        } else {
+            let keep_cols = old_cols.min(new_cols);
+            let mut data = Vec::with_capacity(new_len);
+            let mut old = old.into_iter();
+            for _ in 0..keep_rows {
+                data.extend(old.by_ref().take(keep_cols));
+                old.by_ref().take(old_cols - keep_cols).for_each(drop);
+                data.resize(data.len() + new_cols - keep_cols, fill.clone());
+            }
+            drop(old);
+            data.resize(new_len, fill);
+            data
+        };
+
+        self.data = data;
+        self.num_cols = new_cols;
+        self.num_rows = new_rows;
+    }
 
     /// Switches the values for `num_cols` and `num_rows` _without_ transposing the underlying data.
     pub fn swap_dimensions(&mut self) {
         mem::swap(&mut self.num_cols, &mut self.num_rows);
     }
+
+    /// Transposes the array, swapping rows and columns. The data is copied in `block` x `block` tiles
+    /// to improve cache locality on large arrays.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `block` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// toodee.transpose_blocked(2);
+    /// assert_eq!(toodee.size(), (2, 3));
+    /// assert_eq!(toodee.data(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn transpose_blocked(&mut self, block: usize)
+    where T: Copy + Default {
+        assert!(block > 0);
+        let num_cols = self.num_cols;
+        let num_rows = self.num_rows;
+        if num_cols == num_rows {
+            // no scratch buffer is needed for square arrays
+            transpose_square_in_place(&mut self.data, num_cols, block);
+            return;
+        }
+        let mut transposed = vec![T::default(); self.data.len()];
+        for row_start in (0..num_rows).step_by(block) {
+            let row_end = (row_start + block).min(num_rows);
+            for col_start in (0..num_cols).step_by(block) {
+                let col_end = (col_start + block).min(num_cols);
+                for row in row_start..row_end {
+                    let src = &self.data[row * num_cols + col_start..row * num_cols + col_end];
+                    for (col, v) in (col_start..col_end).zip(src) {
+                        transposed[col * num_rows + row] = *v;
+                    }
+                }
+            }
+        }
+        self.data = transposed;
+        self.swap_dimensions();
+    }
+
+    /// Transposes the array, swapping rows and columns. Unlike `transpose_blocked()`, no tile size
+    /// needs to be chosen: the array is recursively split in half along its longer dimension, which
+    /// gives good cache behaviour at every level of the memory hierarchy. Only square arrays are
+    /// transposed in place. For other arrays, the cells are copied into a single scratch buffer,
+    /// which then replaces the array's storage.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// toodee.transpose_in_place_rect();
+    /// assert_eq!(toodee.size(), (2, 3));
+    /// assert_eq!(toodee.data(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn transpose_in_place_rect(&mut self)
+    where T: Copy + Default {
+        if self.num_cols == self.num_rows {
+            transpose_square_in_place(&mut self.data, self.num_cols, TRANSPOSE_SQUARE_BLOCK);
+            return;
+        }
+        let mut transposed = vec![T::default(); self.data.len()];
+        transpose_recursive(&self.data, &mut transposed, self.num_cols, self.num_rows, 0..self.num_rows, 0..self.num_cols);
+        self.data = transposed;
+        self.swap_dimensions();
+    }
+
+    /// Transposes a square array in place by swapping each cell with its mirror across the
+    /// main diagonal. Unlike the other transpose methods, `T` doesn't need to be `Copy`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the array isn't square, i.e., if `can_transpose_in_place()` is `false`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(2, 2, vec!["a", "b", "c", "d"]);
+    /// toodee.transpose_square();
+    /// assert_eq!(toodee.data(), &["a", "c", "b", "d"]);
+    /// ```
+    pub fn transpose_square(&mut self) {
+        assert!(self.can_transpose_in_place(), "cannot transpose a non-square array in place: it is {}x{}", self.num_cols, self.num_rows);
+        transpose_square_in_place(&mut self.data, self.num_cols, TRANSPOSE_SQUARE_BLOCK);
+    }
+
+    /// Transposes the array, swapping rows and columns, by cloning each cell into a new buffer in
+    /// transposed order. This is the simplest transpose: it works for any `T: Clone`, and for
+    /// non-square arrays, but it isn't cache-friendly on large arrays.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec!["a", "b", "c", "d", "e", "f"]);
+    /// toodee.transpose_naive();
+    /// assert_eq!(toodee.size(), (2, 3));
+    /// assert_eq!(toodee.data(), &["a", "d", "b", "e", "c", "f"]);
+    /// ```
+    pub fn transpose_naive(&mut self)
+    where T: Clone {
+        let num_cols = self.num_cols;
+        let mut transposed = Vec::with_capacity(self.data.len());
+        // each column becomes a row
+        for col in 0..num_cols {
+            transposed.extend(self.data.iter().skip(col).step_by(num_cols).cloned());
+        }
+        self.data = transposed;
+        self.swap_dimensions();
+    }
+}
+
+impl TooDee<char> {
+
+    /// Parses a multi-line string into a grid of characters, one row per line. Lines may end with
+    /// `\n` or `\r\n`, and trailing empty lines are ignored.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `TooDeeError::LengthMismatch` if a line's length (in `char`s) differs from the first line's.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_char_lines("#..\n.#.\n..#\n").unwrap();
+    /// assert_eq!(toodee.size(), (3, 3));
+    /// assert_eq!(toodee[(1, 1)], '#');
+    /// ```
+    pub fn from_char_lines(s: &str) -> Result<TooDee<char>, TooDeeError> {
+        let s = s.trim_end_matches(['\n', '\r']);
+        if s.is_empty() {
+            return Ok(TooDee::default());
+        }
+        let num_cols = s.lines().next().map_or(0, |line| line.chars().count());
+        s.lines().fold(TooDeeBuilder::new(num_cols), |b, line| b.row(line.chars())).build()
+    }
+}
+
+/// Joins the arrays (or views) vertically, in order, into a new `TooDee`. The result is allocated
+/// once, and each row is copied exactly once. Empty arrays are skipped.
+/// 
+/// # Errors
+/// 
+/// Returns `TooDeeError::LengthMismatch` if the non-empty arrays have different numbers of columns,
+/// and `TooDeeError::DimensionOverflow` if the total size overflows.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use toodee::{TooDee,TooDeeOps,vconcat_all};
+/// let a = TooDee::from_vec(2, 1, vec![1, 2]);
+/// let b = TooDee::from_vec(3, 2, vec![0, 3, 4, 0, 5, 6]);
+/// let joined = vconcat_all(&[&a, &b.view((1, 0), (3, 2))]).unwrap();
+/// assert_eq!(joined.size(), (2, 3));
+/// assert_eq!(joined.data(), &[1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn vconcat_all<T>(grids: &[&dyn TooDeeOps<T>]) -> Result<TooDee<T>, TooDeeError>
+where T: Clone {
+    let num_cols = match grids.iter().find(|g| !g.is_empty()) {
+        Some(g) => g.num_cols(),
+        None => return Ok(TooDee::default()),
+    };
+    let mut num_rows = 0usize;
+    for g in grids.iter().filter(|g| !g.is_empty()) {
+        if g.num_cols() != num_cols {
+            return Err(TooDeeError::LengthMismatch { expected: num_cols, actual: g.num_cols() });
+        }
+        num_rows = num_rows.checked_add(g.num_rows()).ok_or(TooDeeError::DimensionOverflow)?;
+    }
+    let mut data = Vec::with_capacity(checked_size(num_cols, num_rows)?);
+    for g in grids {
+        for row in g.rows() {
+            data.extend_from_slice(row);
+        }
+    }
+    Ok(TooDee::from_vec(num_cols, num_rows, data))
+}
+
+/// Parses delimiter-separated values, such as the output of `to_csv()`, into a new `TooDee`. Each
+/// line is a row, and the cells in each row are separated by `sep`. Fields are parsed with
+/// `FromStr`, without any trimming or unquoting. Trailing newlines are ignored, and an empty string
+/// results in an empty array.
+/// 
+/// # Errors
+/// 
+/// Returns `TooDeeError::ParseError`, with the zero-based row and column of the field, if a field
+/// can't be parsed, and `TooDeeError::LengthMismatch` for the first row that has a different number
+/// of fields to the first row.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use toodee::{TooDee,TooDeeOps,TooDeeError,from_csv};
+/// let toodee : TooDee<u32> = from_csv("1,2,3\n4,5,6\n", ',').unwrap();
+/// assert_eq!(toodee.size(), (3, 2));
+/// assert_eq!(toodee[1], [4, 5, 6]);
+/// assert_eq!(from_csv::<u32>("1;2\n3;x", ';'), Err(TooDeeError::ParseError { row: 1, col: 1 }));
+/// ```
+pub fn from_csv<T>(s: &str, sep: char) -> Result<TooDee<T>, TooDeeError>
+where T: FromStr {
+    let s = s.trim_end_matches(['\n', '\r']);
+    if s.is_empty() {
+        return Ok(TooDee::default());
+    }
+    let parse_line = |row: usize, line: &str| line.split(sep).enumerate()
+        .map(|(col, field)| field.parse().map_err(|_| TooDeeError::ParseError { row, col }))
+        .collect::<Result<Vec<T>, _>>();
+    let mut lines = s.lines().enumerate();
+    // the first row determines the number of columns
+    let first = lines.next().map_or(Ok(Vec::new()), |(row, line)| parse_line(row, line))?;
+    let mut builder = TooDeeBuilder::new(first.len()).row(first);
+    for (row, line) in lines {
+        builder = builder.row(parse_line(row, line)?);
+    }
+    builder.build()
+}
+
+/// Below this many cells, `transpose_recursive()` copies the cells directly.
+const TRANSPOSE_LEAF_CELLS : usize = 256;
+
+/// The tile size used by `transpose_square_in_place()` when no block size is specified.
+const TRANSPOSE_SQUARE_BLOCK : usize = 16;
+
+/// Transposes a square `n` x `n` array in place by swapping each cell above the main diagonal
+/// with its mirror below it. The cells are visited in `block` x `block` tiles, so that both sides
+/// of each swap stay in cache.
+fn transpose_square_in_place<T>(data: &mut [T], n: usize, block: usize) {
+    assert_eq!(data.len(), n * n);
+    let p = data.as_mut_ptr();
+    for row_start in (0..n).step_by(block) {
+        let row_end = (row_start + block).min(n);
+        for col_start in (row_start..n).step_by(block) {
+            let col_end = (col_start + block).min(n);
+            for row in row_start..row_end {
+                for col in col_start.max(row + 1)..col_end {
+                    // row < col < n, so both cells are within the array and distinct
+                    unsafe {
+                        ptr::swap_nonoverlapping(p.add(row * n + col), p.add(col * n + row), 1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Transposes the `rows` x `cols` region of `src` (which has `num_cols` columns) into `dst`
+/// (which has `num_rows` columns).
+fn transpose_recursive<T: Copy>(src: &[T], dst: &mut [T], num_cols: usize, num_rows: usize, rows: Range<usize>, cols: Range<usize>) {
+    let row_len = rows.end - rows.start;
+    let col_len = cols.end - cols.start;
+    if row_len * col_len <= TRANSPOSE_LEAF_CELLS {
+        for row in rows {
+            let src_row = &src[row * num_cols + cols.start..row * num_cols + cols.end];
+            for (col, v) in cols.clone().zip(src_row) {
+                dst[col * num_rows + row] = *v;
+            }
+        }
+    } else if row_len >= col_len {
+        let mid = rows.start + row_len / 2;
+        transpose_recursive(src, dst, num_cols, num_rows, rows.start..mid, cols.clone());
+        transpose_recursive(src, dst, num_cols, num_rows, mid..rows.end, cols);
+    } else {
+        let mid = cols.start + col_len / 2;
+        transpose_recursive(src, dst, num_cols, num_rows, rows.clone(), cols.start..mid);
+        transpose_recursive(src, dst, num_cols, num_rows, rows, mid..cols.end);
+    }
 }
 
 /// Use `Vec`'s `IntoIter` for performance reasons.
@@ -128,6 +128,111 @@ pub trait TranslateOps<T> : TooDeeOpsMut<T> {
         
     }
     
+    /// Translate (or scroll) the entire area, as per `translate_with_wrap()`, but return `Err(mid)`
+    /// instead of panicking if `mid` is out of range. The area is left unchanged on error.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TranslateOps};
+    /// let mut toodee : TooDee<u32> = TooDee::from_vec(5, 3, (0..15).collect());
+    /// assert_eq!(toodee.try_translate_with_wrap((1, 1)), Ok(()));
+    /// assert_eq!(toodee[0][0], 6);
+    /// assert_eq!(toodee.try_translate_with_wrap((6, 1)), Err((6, 1)));
+    /// ```
+    fn try_translate_with_wrap(&mut self, mid: Coordinate) -> Result<(), Coordinate> {
+        if mid.0 > self.num_cols() || mid.1 > self.num_rows() {
+            return Err(mid);
+        }
+        self.translate_with_wrap(mid);
+        Ok(())
+    }
+
+    /// Translate (or scroll) the entire area, as per `translate_with_wrap()`, but without wrapping.
+    /// The rectangle of original data from `mid` to the bottom-right corner is moved to (0, 0), and
+    /// the remaining cells (the last `mid.0` columns and the last `mid.1` rows) are set to `fill`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `mid` is beyond the array dimensions.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TranslateOps};
+    /// let mut toodee : TooDee<u32> = TooDee::from_vec(3, 3, (1..=9).collect());
+    /// // move (1, 1) to (0, 0)
+    /// toodee.translate_with_fill((1, 1), &0);
+    /// assert_eq!(toodee.data(), &[5, 6, 0,
+    ///                             8, 9, 0,
+    ///                             0, 0, 0]);
+    /// ```
+    fn translate_with_fill(&mut self, mid: Coordinate, fill: &T)
+    where T: Clone {
+        let (col_mid, row_mid) = mid;
+        let num_cols = self.num_cols();
+        let num_rows = self.num_rows();
+
+        assert!(col_mid <= num_cols);
+        assert!(row_mid <= num_rows);
+
+        self.translate_with_wrap(mid);
+
+        // overwrite the cells that wrapped around
+        let kept_cols = num_cols - col_mid;
+        let kept_rows = num_rows - row_mid;
+        for (r, row) in self.rows_mut().enumerate() {
+            if r < kept_rows {
+                row[kept_cols..].fill(fill.clone());
+            } else {
+                row.fill(fill.clone());
+            }
+        }
+    }
+
+    /// Slide (or scroll) the entire area by `dx` columns and `dy` rows. Positive offsets move the
+    /// data right/down, and negative offsets move it left/up. Cells that are moved beyond the array
+    /// edges are discarded, and the vacated cells are set to `fill`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TranslateOps};
+    /// let mut toodee : TooDee<u32> = TooDee::from_vec(3, 3, (1..=9).collect());
+    /// toodee.slide_with_fill(1, -1, &0);
+    /// assert_eq!(toodee.data(), &[0, 4, 5,
+    ///                             0, 7, 8,
+    ///                             0, 0, 0]);
+    /// ```
+    fn slide_with_fill(&mut self, dx: isize, dy: isize, fill: &T)
+    where T: Clone {
+        let num_cols = self.num_cols();
+        let num_rows = self.num_rows();
+        let dx_abs = dx.unsigned_abs();
+        let dy_abs = dy.unsigned_abs();
+
+        if dx_abs >= num_cols || dy_abs >= num_rows {
+            // nothing is retained
+            self.fill(fill.clone());
+            return;
+        }
+
+        // move the retained cells into place, then overwrite the cells that wrapped around
+        let col_mid = if dx > 0 { num_cols - dx_abs } else { dx_abs };
+        let row_mid = if dy > 0 { num_rows - dy_abs } else { dy_abs };
+        self.translate_with_wrap((col_mid, row_mid));
+
+        let vacated_rows = if dy > 0 { 0..dy_abs } else { num_rows - dy_abs..num_rows };
+        let vacated_cols = if dx > 0 { 0..dx_abs } else { num_cols - dx_abs..num_cols };
+        for (r, row) in self.rows_mut().enumerate() {
+            if vacated_rows.contains(&r) {
+                row.fill(fill.clone());
+            } else {
+                row[vacated_cols.clone()].fill(fill.clone());
+            }
+        }
+    }
+
     /// Flips (or mirrors) the rows.
     /// 
     /// # Examples
@@ -162,6 +267,38 @@ pub trait TranslateOps<T> : TooDeeOpsMut<T> {
             r.reverse();
         }
     }
+
+    /// Reverses the cells within each row, i.e., `flip_cols()`, returning `self` so that calls can be chained.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TranslateOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// toodee.reverse_rows_in_place().reverse_order_of_rows();
+    /// assert_eq!(toodee.data(), &[6, 5, 4, 3, 2, 1]);
+    /// ```
+    fn reverse_rows_in_place(&mut self) -> &mut Self
+    where Self: Sized {
+        self.flip_cols();
+        self
+    }
+
+    /// Reverses the order of the rows, i.e., `flip_rows()`, returning `self` so that calls can be chained.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TranslateOps};
+    /// let mut toodee = TooDee::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// toodee.reverse_order_of_rows();
+    /// assert_eq!(toodee.data(), &[4, 5, 6, 1, 2, 3]);
+    /// ```
+    fn reverse_order_of_rows(&mut self) -> &mut Self
+    where Self: Sized {
+        self.flip_rows();
+        self
+    }
     
 }
 
@@ -1,12 +1,16 @@
 use core::fmt;
 use core::fmt::{Formatter, Debug};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 use core::ops::{Index, IndexMut, Range};
-use core::ptr;
+use core::ptr::{self, NonNull};
 use core::mem;
+use core::slice;
 
 use crate::toodee::*;
 use crate::ops::*;
 use crate::iter::*;
+use crate::error::*;
 
 /// Checks the proposed view dimensions, and returns the correct cols, rows and slice data range
 /// for view construction.
@@ -16,91 +20,76 @@ fn calculate_view_dimensions<T>(start: Coordinate, end: Coordinate, toodee: &imp
     assert!(end.0 <= toodee.num_cols());
     assert!(end.1 <= toodee.num_rows());
     assert!(stride >= toodee.num_cols());
-    let mut num_cols = end.0 - start.0;
-    let mut num_rows = end.1 - start.1;
-    // zero out dimensions for empty arrays
+    let num_cols = end.0 - start.0;
+    let num_rows = end.1 - start.1;
+    // zero out dimensions for empty arrays, and point at the start of the data so that
+    // the range never extends beyond the end of the data
     if num_cols == 0 || num_rows == 0 {
-        num_cols = 0;
-        num_rows = 0;
+        return (0, 0, 0..0);
     }
     let data_start = start.1 * stride + start.0;
-    let data_len = {
-        if num_rows == 0 {
-            0
-        } else {
-            (num_rows - 1) * stride + num_cols
-        }
-    };
+    let data_len = (num_rows - 1) * stride + num_cols;
     (num_cols, num_rows, data_start..data_start + data_len)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloc::vec;
-
-    #[test]
-    fn calc_dims_view() {
-        let v = vec![1u32; 32];
-        let view = TooDeeView::new(4, 4, &v);
-        let (num_cols, num_rows, range) = calculate_view_dimensions((0, 1), (2,3), &view, 4);
-        assert_eq!(num_cols, 2);
-        assert_eq!(num_rows, 2);
-        assert_eq!(range, 4..10);
-    }
-}
-
-/// *Internal only* functions for calculating vector ranges.
-trait TooDeeViewCommon<T>: TooDeeOps<T> {
-
-    fn data(&self) -> &[T];
-
-    fn stride(&self) -> usize;
-
-    fn get_col_params(&self, col: usize) -> (Range<usize>, usize){
-        assert!(col < self.num_cols());
-        let stride = self.stride();
-        let start = col;
-        let end = {
-            let num_rows = self.num_rows();
-            if num_rows == 0 {
-                start
-            } else {
-                start + (num_rows - 1) * stride + 1
-            }
-        };
-        (start..end, stride - 1)
-    }
-
-
-}
-
-impl<T> TooDeeViewCommon<T> for TooDeeView<'_, T> {
-    #[inline]
-    fn data(&self) -> &[T] { &self.data }
-    #[inline]
-    fn stride(&self) -> usize {
-        self.stride
+/// Create a new `TooDeeView` over a slice whose rows are `stride` elements apart, e.g.,
+/// an image buffer with padding at the end of each row. The padding is never accessed.
+///
+/// Returns `None` if one of the dimensions is zero but the other is non-zero, if `stride`
+/// is less than `num_cols`, or if the slice is too short to hold the last row.
+///
+/// # Examples
+///
+/// ```
+/// use toodee::{view_with_stride,TooDeeOps};
+/// // two rows of three cells, each padded to a stride of four
+/// let data = [1, 2, 3, 0, 4, 5, 6];
+/// let view = view_with_stride(3, 2, 4, &data).unwrap();
+/// assert_eq!(view[1], [4, 5, 6]);
+/// assert!(view_with_stride(3, 2, 4, &data[..6]).is_none());
+/// ```
+pub fn view_with_stride<T>(num_cols: usize, num_rows: usize, stride: usize, data: &[T]) -> Option<TooDeeView<'_, T>> {
+    checked_size(num_cols, num_rows).ok()?;
+    if stride < num_cols {
+        return None;
+    }
+    let required = match num_rows {
+        0 => 0,
+        _ => (num_rows - 1).checked_mul(stride)?.checked_add(num_cols)?,
+    };
+    if required > data.len() {
+        return None;
     }
-}
-
-impl<T> TooDeeViewCommon<T> for TooDeeViewMut<'_, T> {
-    #[inline]
-    fn data(&self) -> &[T] { &self.data }
-    #[inline]
-    fn stride(&self) -> usize {
-        self.stride
+    unsafe {
+        Some(TooDeeView::from_raw_parts(data.as_ptr(), num_cols, num_rows, stride, (0, 0)))
     }
 }
 
-
 /// Provides a read-only view (or subset) of a `TooDee` array.
-#[derive(Copy, Clone, Hash, Eq, PartialEq)]
 pub struct TooDeeView<'a, T> {
-    data: &'a [T],
+    /// Points to the first cell. Rows are `stride` elements apart, and the elements between
+    /// rows are never accessed, so they may belong to another view.
+    ptr: NonNull<T>,
     num_cols: usize,
     num_rows: usize,
     stride: usize,
+    /// The coordinate of the first cell within the parent `TooDee` (or slice).
+    origin: Coordinate,
+    _marker: PhantomData<&'a T>,
+}
+
+// NonNull is !Sync, so we need to implement Sync manually
+unsafe impl<T: Sync> Sync for TooDeeView<'_, T> {}
+
+// NonNull is !Send, so we need to implement Send manually
+unsafe impl<T: Sync> Send for TooDeeView<'_, T> {}
+
+impl<T> Copy for TooDeeView<'_, T> {}
+
+impl<T> Clone for TooDeeView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
 impl<'a, T> TooDeeView<'a, T> {
@@ -124,16 +113,86 @@ impl<'a, T> TooDeeView<'a, T> {
     /// let view = TooDeeView::new(4, 3, &data);
     /// ```
     pub fn new(num_cols: usize, num_rows: usize, data: &'a [T]) -> TooDeeView<'a, T> {
-        if num_cols == 0 || num_rows == 0 {
-            assert_eq!(num_rows, num_cols);
+        let size = checked_size(num_cols, num_rows).unwrap_or_else(|e| panic!("{}", e));
+        if size > data.len() {
+            panic!("{}", TooDeeError::LengthMismatch { expected: size, actual: data.len() });
         }
-        let size = num_cols.checked_mul(num_rows).unwrap();
-        assert!(size <= data.len());
+        unsafe {
+            TooDeeView::from_raw_parts(data.as_ptr(), num_cols, num_rows, num_cols, (0, 0))
+        }
+    }
+
+
+    /// Creates a view from its raw parts.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `num_rows` rows, each `num_cols` elements long and
+    /// `stride` elements apart, for the lifetime `'a`.
+    #[inline]
+    unsafe fn from_raw_parts(ptr: *const T, num_cols: usize, num_rows: usize, stride: usize, origin: Coordinate) -> TooDeeView<'a, T> {
         TooDeeView {
-            data: &data[..size],
+            ptr: NonNull::new_unchecked(ptr as *mut T),
             num_cols,
             num_rows,
-            stride: num_cols,
+            stride,
+            origin,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Converts a coordinate within the view to the corresponding coordinate within the parent
+    /// `TooDee` (or slice) that the view was originally created from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// let view = toodee.view((2, 3), (8, 5));
+    /// assert_eq!(view.parent_coord((1, 1)), (3, 4));
+    /// let inner = view.view((1, 0), (4, 2));
+    /// assert_eq!(inner.parent_coord((0, 0)), (3, 3));
+    /// ```
+    pub fn parent_coord(&self, local: Coordinate) -> Coordinate {
+        (self.origin.0 + local.0, self.origin.1 + local.1)
+    }
+
+    /// Returns an iterator over all cells in the view, along with each cell's coordinate within the
+    /// parent `TooDee` (or slice) that the view was originally created from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps};
+    /// let toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+    /// let view = toodee.view((2, 1), (4, 3));
+    /// let mut iter = view.indexed_cells_parent();
+    /// assert_eq!(iter.next(), Some(((2, 1), &6)));
+    /// assert_eq!(iter.next(), Some(((3, 1), &7)));
+    /// assert_eq!(iter.next(), Some(((2, 2), &10)));
+    /// ```
+    pub fn indexed_cells_parent(&self) -> IndexedCells<'a, T> {
+        let rows = unsafe { Rows::new(self.ptr.as_ptr(), self.num_rows, self.num_cols, self.stride) };
+        IndexedCells::new(rows.into_cells(), self.origin)
+    }
+
+
+    /// Creates a view within this view. Unlike `TooDeeOps::view()`, the new view has the full
+    /// lifetime `'a`, rather than borrowing `self`.
+    pub(super) fn sub_view(&self, start: Coordinate, end: Coordinate) -> TooDeeView<'a, T> {
+        let (num_cols, num_rows, data_range) = calculate_view_dimensions(start, end, self, self.stride);
+        unsafe {
+            TooDeeView::from_raw_parts(self.ptr.as_ptr().add(data_range.start), num_cols, num_rows, self.stride, self.parent_coord(start))
+        }
+    }
+
+    /// Returns a reference to the cell at `coord` that has the full lifetime `'a`, rather than
+    /// borrowing `self`.
+    pub(super) fn cell(&self, coord: Coordinate) -> &'a T {
+        assert!(coord.0 < self.num_cols && coord.1 < self.num_rows);
+        unsafe {
+            &*self.ptr.as_ptr().add(coord.1 * self.stride + coord.0)
         }
     }
 
@@ -142,12 +201,7 @@ impl<'a, T> TooDeeView<'a, T> {
         let stride = toodee.num_cols();
         let (num_cols, num_rows, data_range) = calculate_view_dimensions(start, end, toodee, stride);
         unsafe {
-            TooDeeView {
-                data: toodee.data().get_unchecked(data_range),
-                num_cols,
-                num_rows,
-                stride,
-            }
+            TooDeeView::from_raw_parts(toodee.data().as_ptr().add(data_range.start), num_cols, num_rows, stride, start)
         }
     }
 }
@@ -165,32 +219,19 @@ impl<'a, T> TooDeeOps<T> for TooDeeView<'a, T>
     }
 
     fn view(&self, start: Coordinate, end: Coordinate) -> TooDeeView<'_, T> {
-        let (num_cols, num_rows, data_range) = calculate_view_dimensions(start, end, self, self.stride);
-        unsafe {
-            TooDeeView {
-                data: self.data.get_unchecked(data_range),
-                num_cols,
-                num_rows,
-                stride: self.stride,
-            }
-        }
+        self.sub_view(start, end)
     }
 
     fn rows(&self) -> Rows<'_, T> {
-        Rows {
-            v: self.data,
-            cols: self.num_cols,
-            skip_cols: self.stride - self.num_cols,
+        unsafe {
+            Rows::new(self.ptr.as_ptr(), self.num_rows, self.num_cols, self.stride)
         }
     }
 
     fn col(&self, col: usize) -> Col<'_, T> {
-        let (data_range, skip) = self.get_col_params(col);
+        assert!(col < self.num_cols);
         unsafe {
-            Col {
-                v: self.data.get_unchecked(data_range),
-                skip,
-            }
+            Col::new(self.ptr.as_ptr().add(col), self.num_rows, self.stride)
         }
     }
 
@@ -207,7 +248,7 @@ impl<'a, T> TooDeeOps<T> for TooDeeView<'a, T>
     /// ```
     unsafe fn get_unchecked_row(&self, row: usize) -> &[T] {
         let start = row * self.stride;
-        self.data.get_unchecked(start..start + self.num_cols)
+        slice::from_raw_parts(self.ptr.as_ptr().add(start), self.num_cols)
     }
 
     /// # Examples
@@ -221,7 +262,7 @@ impl<'a, T> TooDeeOps<T> for TooDeeView<'a, T>
     /// }
     /// ```
     unsafe fn get_unchecked(&self, coord: Coordinate) -> &T {
-        self.data.get_unchecked(coord.1 * self.stride + coord.0)
+        &*self.ptr.as_ptr().add(coord.1 * self.stride + coord.0)
     }
 }
 
@@ -232,7 +273,7 @@ impl<'a, T> Index<usize> for TooDeeView<'a, T> {
         assert!(row < self.num_rows);
         let start = row * self.stride;
         unsafe {
-            self.data.get_unchecked(start..start + self.num_cols)
+            slice::from_raw_parts(self.ptr.as_ptr().add(start), self.num_cols)
         }
     }
 }
@@ -245,21 +286,31 @@ impl<'a, T> Index<Coordinate> for TooDeeView<'a, T> {
         assert!(coord.0 < self.num_cols);
         // can access the element unchecked because the above assertions hold
         unsafe {
-            self.data.get_unchecked(coord.1 * self.stride + coord.0)
+            &*self.ptr.as_ptr().add(coord.1 * self.stride + coord.0)
         }
     }
 }
 
 
 /// Provides a mutable view (or subset), of a `TooDee` array.
-#[derive(Hash, Eq, PartialEq)]
 pub struct TooDeeViewMut<'a, T> {
-    data: &'a mut [T],
+    /// Points to the first cell. Rows are `stride` elements apart, and the elements between
+    /// rows are never accessed, so they may belong to another view.
+    ptr: NonNull<T>,
     num_cols: usize,
     num_rows: usize,
     stride: usize,
+    /// The coordinate of the first cell within the parent `TooDee` (or slice).
+    origin: Coordinate,
+    _marker: PhantomData<&'a mut T>,
 }
 
+// NonNull is !Sync, so we need to implement Sync manually
+unsafe impl<T: Sync> Sync for TooDeeViewMut<'_, T> {}
+
+// NonNull is !Send, so we need to implement Send manually
+unsafe impl<T: Send> Send for TooDeeViewMut<'_, T> {}
+
 
 impl<'a, T> TooDeeViewMut<'a, T> {
     /// Create a new `TooDeeViewMut` using the provided mutable slice reference.
@@ -282,32 +333,144 @@ impl<'a, T> TooDeeViewMut<'a, T> {
     /// let view_mut = TooDeeViewMut::new(4, 3, &mut data);
     /// ```
     pub fn new(num_cols: usize, num_rows: usize, data: &'a mut [T]) -> TooDeeViewMut<'a, T> {
-        if num_cols == 0 || num_rows == 0 {
-            assert_eq!(num_rows, num_cols);
+        let size = checked_size(num_cols, num_rows).unwrap_or_else(|e| panic!("{}", e));
+        if size > data.len() {
+            panic!("{}", TooDeeError::LengthMismatch { expected: size, actual: data.len() });
         }
-        let size = num_cols.checked_mul(num_rows).unwrap();
-        assert!(size <= data.len());
         unsafe {
-            TooDeeViewMut {
-                data: data.get_unchecked_mut(..size),
-                num_cols,
-                num_rows,
-                stride: num_cols,
-            }
+            TooDeeViewMut::from_raw_parts(data.as_mut_ptr(), num_cols, num_rows, num_cols, (0, 0))
+        }
+    }
+
+    /// Creates a mutable view from its raw parts.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `num_rows` rows, each `num_cols` elements long
+    /// and `stride` elements apart, and those elements must not be accessed by anything else for
+    /// the lifetime `'a`.
+    #[inline]
+    unsafe fn from_raw_parts(ptr: *mut T, num_cols: usize, num_rows: usize, stride: usize, origin: Coordinate) -> TooDeeViewMut<'a, T> {
+        TooDeeViewMut {
+            ptr: NonNull::new_unchecked(ptr),
+            num_cols,
+            num_rows,
+            stride,
+            origin,
+            _marker: PhantomData,
         }
     }
 
+    /// Converts a coordinate within the view to the corresponding coordinate within the parent
+    /// `TooDee` (or slice) that the view was originally created from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee : TooDee<u32> = TooDee::new(10, 5);
+    /// let view = toodee.view_mut((2, 3), (8, 5));
+    /// assert_eq!(view.parent_coord((1, 1)), (3, 4));
+    /// ```
+    pub fn parent_coord(&self, local: Coordinate) -> Coordinate {
+        (self.origin.0 + local.0, self.origin.1 + local.1)
+    }
+
+    /// Returns an iterator over all cells in the view, along with each cell's coordinate within the
+    /// parent `TooDee` (or slice) that the view was originally created from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use toodee::{TooDee,TooDeeOps,TooDeeOpsMut};
+    /// let mut toodee = TooDee::from_vec(4, 3, (0u32..12).collect());
+    /// let view = toodee.view_mut((2, 1), (4, 3));
+    /// let coords : Vec<_> = view.indexed_cells_parent().map(|(coord, _)| coord).collect();
+    /// assert_eq!(coords, vec![(2, 1), (3, 1), (2, 2), (3, 2)]);
+    /// ```
+    pub fn indexed_cells_parent(&self) -> IndexedCells<'_, T> {
+        IndexedCells::new(self.cells(), self.origin)
+    }
+
+    /// Creates a mutable view within this view that has the full lifetime `'a`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the returned view is not aliased, i.e., that it doesn't overlap
+    /// any other live view, and that `self` isn't used to access its cells while it is alive.
+    pub(super) unsafe fn sub_view_mut(&self, start: Coordinate, end: Coordinate) -> TooDeeViewMut<'a, T> {
+        let (num_cols, num_rows, data_range) = calculate_view_dimensions(start, end, self, self.stride);
+        TooDeeViewMut::from_raw_parts(self.ptr.as_ptr().add(data_range.start), num_cols, num_rows, self.stride, self.parent_coord(start))
+    }
+
     /// Used internally by `TooDee` to create a `TooDeeViewMut`.
     pub(super) fn from_toodee(start: Coordinate, end: Coordinate, toodee: &'a mut TooDee<T>) -> TooDeeViewMut<'a, T> {
         let stride = toodee.num_cols();
         let (num_cols, num_rows, data_range) = calculate_view_dimensions(start, end, toodee, stride);
         unsafe {
-            TooDeeViewMut {
-                data: toodee.data_mut().get_unchecked_mut(data_range),
-                num_cols,
-                num_rows,
-                stride,
-            }
+            TooDeeViewMut::from_raw_parts(toodee.data_mut().as_mut_ptr().add(data_range.start), num_cols, num_rows, stride, start)
+        }
+    }
+
+    /// Splits the view into two disjoint mutable views at the specified row. Used by
+    /// `TooDeeOpsMut::split_at_row_mut()`.
+    pub(crate) fn into_split_at_row(self, row: usize) -> (TooDeeViewMut<'a, T>, TooDeeViewMut<'a, T>) {
+        assert!(row <= self.num_rows);
+        let bottom_rows = self.num_rows - row;
+        // zero out dimensions for empty views
+        let top_cols = if row == 0 { 0 } else { self.num_cols };
+        let bottom_cols = if bottom_rows == 0 { 0 } else { self.num_cols };
+        // an empty bottom view may start beyond the end of the buffer, so don't offset its pointer
+        let offset = if bottom_rows == 0 { 0 } else { row * self.stride };
+        unsafe {
+            let ptr = self.ptr.as_ptr();
+            (
+                TooDeeViewMut::from_raw_parts(ptr, top_cols, row, self.stride, self.origin),
+                TooDeeViewMut::from_raw_parts(ptr.add(offset), bottom_cols, bottom_rows, self.stride, self.parent_coord((0, row))),
+            )
+        }
+    }
+
+    /// Splits the view into two disjoint mutable views at the specified column. Used by
+    /// `TooDeeOpsMut::split_at_col_mut()`.
+    ///
+    /// Both views keep the original stride, so their rows are interleaved in the underlying
+    /// buffer. This is sound because a view only ever accesses its own cells, and never forms a
+    /// reference that spans the gap between rows.
+    pub(crate) fn into_split_at_col(self, col: usize) -> (TooDeeViewMut<'a, T>, TooDeeViewMut<'a, T>) {
+        assert!(col <= self.num_cols);
+        let right_cols = self.num_cols - col;
+        // zero out dimensions for empty views
+        let left_rows = if col == 0 { 0 } else { self.num_rows };
+        let right_rows = if right_cols == 0 { 0 } else { self.num_rows };
+        unsafe {
+            let ptr = self.ptr.as_ptr();
+            (
+                TooDeeViewMut::from_raw_parts(ptr, col, left_rows, self.stride, self.origin),
+                TooDeeViewMut::from_raw_parts(ptr.add(col), right_cols, right_rows, self.stride, self.parent_coord((col, 0))),
+            )
+        }
+    }
+
+    /// Splits the view into two mutable sub-views, provided that the `(start, end)` areas don't
+    /// overlap. Used by `TooDeeOpsMut::two_views_mut()`.
+    pub(crate) fn into_two_views(self, a: (Coordinate, Coordinate), b: (Coordinate, Coordinate)) -> Option<(TooDeeViewMut<'a, T>, TooDeeViewMut<'a, T>)> {
+        let (a_cols, a_rows, a_range) = calculate_view_dimensions(a.0, a.1, &self, self.stride);
+        let (b_cols, b_rows, b_range) = calculate_view_dimensions(b.0, b.1, &self, self.stride);
+        // empty areas never overlap
+        let overlaps = a_cols > 0 && b_cols > 0
+            && a.0.0 < b.1.0 && b.0.0 < a.1.0
+            && a.0.1 < b.1.1 && b.0.1 < a.1.1;
+        if overlaps {
+            return None;
+        }
+        let ptr = self.ptr.as_ptr();
+        // the areas are disjoint, so the views will never access the same cells
+        unsafe {
+            Some((
+                TooDeeViewMut::from_raw_parts(ptr.add(a_range.start), a_cols, a_rows, self.stride, self.parent_coord(a.0)),
+                TooDeeViewMut::from_raw_parts(ptr.add(b_range.start), b_cols, b_rows, self.stride, self.parent_coord(b.0)),
+            ))
         }
     }
 }
@@ -326,29 +489,21 @@ impl<'a, T> TooDeeOps<T> for TooDeeViewMut<'a, T> {
 
     fn view(&self, start: Coordinate, end: Coordinate) -> TooDeeView<'_, T> {
         let (num_cols, num_rows, data_range) = calculate_view_dimensions(start, end, self, self.stride);
-        TooDeeView {
-            data: &self.data[data_range],
-            num_cols,
-            num_rows,
-            stride: self.stride,
+        unsafe {
+            TooDeeView::from_raw_parts(self.ptr.as_ptr().add(data_range.start), num_cols, num_rows, self.stride, self.parent_coord(start))
         }
     }
 
     fn rows(&self) -> Rows<'_, T> {
-        Rows {
-            v: self.data,
-            cols: self.num_cols,
-            skip_cols: self.stride - self.num_cols,
+        unsafe {
+            Rows::new(self.ptr.as_ptr(), self.num_rows, self.num_cols, self.stride)
         }
     }
 
     fn col(&self, col: usize) -> Col<'_, T> {
-        let (data_range, skip) = self.get_col_params(col);
+        assert!(col < self.num_cols);
         unsafe {
-            Col {
-                v: self.data.get_unchecked(data_range),
-                skip,
-            }
+            Col::new(self.ptr.as_ptr().add(col), self.num_rows, self.stride)
         }
     }
 
@@ -365,7 +520,7 @@ impl<'a, T> TooDeeOps<T> for TooDeeViewMut<'a, T> {
     /// ```
     unsafe fn get_unchecked_row(&self, row: usize) -> &[T] {
         let start = row * self.stride;
-        self.data.get_unchecked(start..start + self.num_cols)
+        slice::from_raw_parts(self.ptr.as_ptr().add(start), self.num_cols)
     }
 
     /// # Examples
@@ -379,38 +534,27 @@ impl<'a, T> TooDeeOps<T> for TooDeeViewMut<'a, T> {
     /// }
     /// ```
     unsafe fn get_unchecked(&self, coord: Coordinate) -> &T {
-        self.data.get_unchecked(coord.1 * self.stride + coord.0)
+        &*self.ptr.as_ptr().add(coord.1 * self.stride + coord.0)
     }
 }
 
 impl<'a, T> TooDeeOpsMut<T> for TooDeeViewMut<'a, T> {
     fn view_mut(&mut self, start: Coordinate, end: Coordinate) -> TooDeeViewMut<'_, T> {
-        let (num_cols, num_rows, data_range) = calculate_view_dimensions(start, end, self, self.stride);
         unsafe {
-            TooDeeViewMut {
-                data: self.data.get_unchecked_mut(data_range),
-                num_cols,
-                num_rows,
-                stride: self.stride,
-            }
+            self.sub_view_mut(start, end)
         }
     }
 
     fn rows_mut(&mut self) -> RowsMut<'_, T> {
-        RowsMut {
-            v: self.data,
-            cols: self.num_cols,
-            skip_cols: self.stride - self.num_cols,
+        unsafe {
+            RowsMut::new(self.ptr.as_ptr(), self.num_rows, self.num_cols, self.stride)
         }
     }
 
     fn col_mut(&mut self, col: usize) -> ColMut<'_, T> {
-        let (data_range, skip) = self.get_col_params(col);
+        assert!(col < self.num_cols);
         unsafe {
-            ColMut {
-                v: self.data.get_unchecked_mut(data_range),
-                skip,
-            }
+            ColMut::new(self.ptr.as_ptr().add(col), self.num_rows, self.stride)
         }
     }
 
@@ -438,16 +582,11 @@ impl<'a, T> TooDeeOpsMut<T> for TooDeeViewMut<'a, T> {
             mem::swap(&mut r1, &mut r2);
         }
         assert!(r2 < self.num_rows);
-        let num_cols = self.num_cols;
         unsafe {
-            let (first, rest) = self.data.get_unchecked_mut(r1 * self.stride..).split_at_mut(num_cols);
-            let snd_idx = (r2 - r1) * self.stride - num_cols;
-            let second = rest.get_unchecked_mut(snd_idx..snd_idx + num_cols);
-            // Both slices are guaranteed to have the same length
-            debug_assert_eq!(first.len(), num_cols);
-            debug_assert_eq!(second.len(), num_cols);
-            // We know that the two slices will not overlap because r1 != r2, and we used split_at_mut()
-            ptr::swap_nonoverlapping(first.as_mut_ptr(), second.as_mut_ptr(), num_cols);
+            let first = self.ptr.as_ptr().add(r1 * self.stride);
+            let second = self.ptr.as_ptr().add(r2 * self.stride);
+            // We know that the two rows will not overlap because r1 != r2, and stride >= num_cols
+            ptr::swap_nonoverlapping(first, second, self.num_cols);
         }
     }
 
@@ -464,7 +603,7 @@ impl<'a, T> TooDeeOpsMut<T> for TooDeeViewMut<'a, T> {
     /// ```
     unsafe fn get_unchecked_row_mut(&mut self, row: usize) -> &mut [T] {
         let start = row * self.stride;
-        self.data.get_unchecked_mut(start..start + self.num_cols)
+        slice::from_raw_parts_mut(self.ptr.as_ptr().add(start), self.num_cols)
     }
 
 
@@ -479,7 +618,7 @@ impl<'a, T> TooDeeOpsMut<T> for TooDeeViewMut<'a, T> {
     /// }
     /// ```
     unsafe fn get_unchecked_mut(&mut self, coord: Coordinate) -> &mut T {
-        self.data.get_unchecked_mut(coord.1 * self.stride + coord.0)
+        &mut *self.ptr.as_ptr().add(coord.1 * self.stride + coord.0)
     }
 }
 
@@ -489,7 +628,7 @@ impl<'a, T> Index<usize> for TooDeeViewMut<'a, T> {
         assert!(row < self.num_rows);
         let start = row * self.stride;
         unsafe {
-            self.data.get_unchecked(start..start + self.num_cols)
+            slice::from_raw_parts(self.ptr.as_ptr().add(start), self.num_cols)
         }
     }
 }
@@ -501,7 +640,7 @@ impl<'a, T> Index<Coordinate> for TooDeeViewMut<'a, T> {
         assert!(coord.0 < self.num_cols);
         // can access the element unchecked because the above assertions hold
         unsafe {
-            self.data.get_unchecked(coord.1 * self.stride + coord.0)
+            &*self.ptr.as_ptr().add(coord.1 * self.stride + coord.0)
         }
     }
 }
@@ -511,7 +650,7 @@ impl<'a, T> IndexMut<usize> for TooDeeViewMut<'a, T> {
         assert!(row < self.num_rows);
         let start = row * self.stride;
         unsafe {
-            self.data.get_unchecked_mut(start..start + self.num_cols)
+            slice::from_raw_parts_mut(self.ptr.as_ptr().add(start), self.num_cols)
         }
     }
 }
@@ -522,22 +661,49 @@ impl<'a, T> IndexMut<Coordinate> for TooDeeViewMut<'a, T> {
         assert!(coord.0 < self.num_cols);
         // can access the element unchecked because the above assertions hold
         unsafe {
-            self.data.get_unchecked_mut(coord.1 * self.stride + coord.0)
+            &mut *self.ptr.as_ptr().add(coord.1 * self.stride + coord.0)
         }
     }
 }
 
 impl<'a, T> From<TooDeeViewMut<'a, T>> for TooDeeView<'a, T> {
     fn from(v: TooDeeViewMut<'a, T>) -> TooDeeView<'a, T> {
-        TooDeeView {
-            data: v.data,
-            num_cols: v.num_cols,
-            num_rows: v.num_rows,
-            stride: v.stride,
+        unsafe {
+            TooDeeView::from_raw_parts(v.ptr.as_ptr(), v.num_cols, v.num_rows, v.stride, v.origin)
         }
     }
 }
 
+impl<T> PartialEq for TooDeeView<'_, T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.rows().eq(other.rows())
+    }
+}
+
+impl<T> Eq for TooDeeView<'_, T> where T: Eq {}
+
+impl<T> Hash for TooDeeView<'_, T> where T: Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size().hash(state);
+        self.rows().for_each(|r| r.hash(state));
+    }
+}
+
+impl<T> PartialEq for TooDeeViewMut<'_, T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.rows().eq(other.rows())
+    }
+}
+
+impl<T> Eq for TooDeeViewMut<'_, T> where T: Eq {}
+
+impl<T> Hash for TooDeeViewMut<'_, T> where T: Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size().hash(state);
+        self.rows().for_each(|r| r.hash(state));
+    }
+}
+
 impl<'a, T> IntoIterator for &'a TooDeeView<'a, T> {
     type Item = &'a T;
     type IntoIter = Cells<'a, T>;
@@ -573,3 +739,19 @@ impl<T> Debug for TooDeeViewMut<'_, T> where T: Debug {
         f.debug_list().entries(self.rows()).finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn calc_dims_view() {
+        let v = vec![1u32; 32];
+        let view = TooDeeView::new(4, 4, &v);
+        let (num_cols, num_rows, range) = calculate_view_dimensions((0, 1), (2,3), &view, 4);
+        assert_eq!(num_cols, 2);
+        assert_eq!(num_rows, 2);
+        assert_eq!(range, 4..10);
+    }
+}